@@ -0,0 +1,94 @@
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+/// `name` made safe to use in a test name
+fn ident(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    examples(Path::new(&root));
+    fuzz_regressions(Path::new(&root));
+}
+
+// Generate one test per `examples/dayNN/NAME.txt` for `tests/examples.rs`
+fn examples(root: &Path) {
+    let examples = root.join("examples");
+    println!("cargo:rerun-if-changed={}", examples.display());
+
+    let mut cases = vec![];
+    if let Ok(dirs) = fs::read_dir(&examples) {
+        for dir in dirs.flatten() {
+            println!("cargo:rerun-if-changed={}", dir.path().display());
+            let name = dir.file_name().to_string_lossy().into_owned();
+            let day: u8 = match name.strip_prefix("day").and_then(|d| d.parse().ok()) {
+                Some(day) => day,
+                None => continue,
+            };
+            for file in fs::read_dir(dir.path()).unwrap().flatten() {
+                let path = file.path();
+                if path.extension() == Some(OsStr::new("txt")) {
+                    cases.push((day, path.with_extension("")));
+                }
+            }
+        }
+    }
+    cases.sort();
+
+    let mut tests = String::new();
+    for (day, case) in cases {
+        let stem = case.file_name().unwrap().to_string_lossy();
+        tests.push_str(&format!(
+            "#[test]\nfn day{:02}_{}() -> Result<()> {{\n    check_example({}, {:?})\n}}\n\n",
+            day,
+            ident(&stem),
+            day,
+            case.display().to_string()
+        ));
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+// Generate one test per `fuzz/regressions/TARGET/NAME` for `tests/fuzz_regressions.rs`
+fn fuzz_regressions(root: &Path) {
+    let regressions = root.join("fuzz").join("regressions");
+    println!("cargo:rerun-if-changed={}", regressions.display());
+
+    let mut cases = vec![];
+    if let Ok(dirs) = fs::read_dir(&regressions) {
+        for dir in dirs.flatten() {
+            println!("cargo:rerun-if-changed={}", dir.path().display());
+            let target = dir.file_name().to_string_lossy().into_owned();
+            for file in fs::read_dir(dir.path()).unwrap().flatten() {
+                cases.push((target.clone(), file.path()));
+            }
+        }
+    }
+    cases.sort();
+
+    let mut tests = String::new();
+    for (target, case) in cases {
+        let name = case.file_name().unwrap().to_string_lossy();
+        tests.push_str(&format!(
+            "#[test]\nfn {}_{}() -> Result<()> {{\n    replay(targets::{}, {:?})\n}}\n\n",
+            target,
+            ident(&name),
+            target,
+            case.display().to_string()
+        ));
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("fuzz_regressions.rs");
+    fs::write(out, tests).unwrap();
+}
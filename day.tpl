@@ -1,27 +1,41 @@
+use crate::Solution;
 use anyhow::Result;
-use aoc2021::dispatch;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct DayNN;
 
-fn part1(_input: &str) -> Result<i32> {
-    Ok(0)
-}
+impl Solution for DayNN {
+    type Input = String;
+    type Part1 = {part1_type};
+    type Part2 = {part2_type};
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String) -> Result<{part1_type}> {
+        Ok({part1_default})
+    }
 
-fn part2(_input: &str) -> Result<i32> {
-    Ok(0)
+    fn part2(_input: &String) -> Result<{part2_type}> {
+        Ok({part2_default})
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "";
+    const TEST_INPUT: &str = {test_input};
 
     #[test]
     fn test_part1() -> Result<()> {
-        assert_eq!(part1(TEST_INPUT)?, 0);
+        assert_eq!(DayNN::part1(&DayNN::parse(TEST_INPUT)?)?, {part1});
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(DayNN::part2(&DayNN::parse(TEST_INPUT)?)?, {part2});
         Ok(())
     }
 }
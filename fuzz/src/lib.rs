@@ -0,0 +1,110 @@
+//! What each fuzz target does with its input. Kept out of `fuzz_targets/` so that
+//! `tests/fuzz_regressions.rs` in the main crate can replay saved crashes through them.
+use aoc2021::cpu::{Cpu, CpuState};
+use aoc2021::days::*;
+use aoc2021::Solution;
+use std::str::{self, FromStr};
+
+/// How many instructions `cpu_run` executes between outputs before giving up on a program
+const STEPS: usize = 10_000;
+/// How many times `cpu_run` resumes a program after an output or for more input
+const RESUMES: i64 = 100;
+
+fn text(data: &[u8]) -> Option<&str> {
+    str::from_utf8(data).ok()
+}
+
+pub fn cpu_parse(data: &[u8]) {
+    if let Some(s) = text(data) {
+        let _ = Cpu::from_str(s);
+    }
+}
+
+/// Run `data` as a program, two bytes per value so that op codes and addresses
+/// come up often, feeding it inputs until it halts, fails or runs out of steps
+pub fn cpu_run(data: &[u8]) {
+    let program = data
+        .chunks_exact(2)
+        .map(|c| i16::from_le_bytes([c[0], c[1]]) as i64)
+        .collect();
+    let mut cpu = Cpu::new(program);
+    for input in 0..RESUMES {
+        cpu.enqueue_input(input);
+        match cpu.run_for(STEPS) {
+            Ok(CpuState::Halted) | Err(_) => break,
+            Ok(CpuState::Output(_)) | Ok(CpuState::NeedsInput) => {}
+        }
+    }
+}
+
+pub fn day16(data: &[u8]) {
+    if let Some(s) = text(data) {
+        if let Ok(packet) = Day16::parse(s) {
+            let _ = Day16::part1(&packet);
+            let _ = Day16::part2(&packet);
+        }
+    }
+}
+
+pub fn day18(data: &[u8]) {
+    if let Some(s) = text(data) {
+        if let Ok(numbers) = Day18::parse(s) {
+            let _ = Day18::part1(&numbers);
+            let _ = Day18::part2(&numbers);
+        }
+    }
+}
+
+pub fn day22(data: &[u8]) {
+    if let Some(s) = text(data) {
+        if let Ok(steps) = Day22::parse(s) {
+            let _ = Day22::part1(&steps);
+            let _ = Day22::part2(&steps);
+        }
+    }
+}
+
+pub fn day23(data: &[u8]) {
+    if let Some(s) = text(data) {
+        let _ = Day23::parse(s);
+    }
+}
+
+/// The first byte picks the day, the rest is its input. Day 24 searches for model
+/// numbers while parsing, so it's left to its own tests
+pub fn parse(data: &[u8]) {
+    let (day, s) = match data.split_first() {
+        Some((&day, rest)) => match text(rest) {
+            Some(s) => (day % 25 + 1, s),
+            None => return,
+        },
+        None => return,
+    };
+    match day {
+        1 => drop(Day01::parse(s)),
+        2 => drop(Day02::parse(s)),
+        3 => drop(Day03::parse(s)),
+        4 => drop(Day04::parse(s)),
+        5 => drop(Day05::parse(s)),
+        6 => drop(Day06::parse(s)),
+        7 => drop(Day07::parse(s)),
+        8 => drop(Day08::parse(s)),
+        9 => drop(Day09::parse(s)),
+        10 => drop(Day10::parse(s)),
+        11 => drop(Day11::parse(s)),
+        12 => drop(Day12::parse(s)),
+        13 => drop(Day13::parse(s)),
+        14 => drop(Day14::parse(s)),
+        15 => drop(Day15::parse(s)),
+        16 => drop(Day16::parse(s)),
+        17 => drop(Day17::parse(s)),
+        18 => drop(Day18::parse(s)),
+        19 => drop(Day19::parse(s)),
+        20 => drop(Day20::parse(s)),
+        21 => drop(Day21::parse(s)),
+        22 => drop(Day22::parse(s)),
+        23 => drop(Day23::parse(s)),
+        25 => drop(Day25::parse(s)),
+        _ => {}
+    }
+}
@@ -0,0 +1,124 @@
+use crate::solution::Part;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Outcome of comparing an answer against the recorded one
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Check {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+fn path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}.toml", day))
+}
+
+fn key(part: Part) -> &'static str {
+    match part {
+        Part::Part1 => "part1",
+        Part::Part2 => "part2",
+    }
+}
+
+fn load_file(path: &Path) -> Result<Table> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+    let raw = fs::read_to_string(path).context(format!("reading `{}`", path.display()))?;
+    raw.parse()
+        .context(format!("invalid answers file `{}`", path.display()))
+}
+
+fn load(dir: &Path, day: u8) -> Result<Table> {
+    load_file(&path(dir, day))
+}
+
+/// The answer for a part in a file with `part1` and `part2` keys, if there is one
+pub fn expected_in(path: &Path, part: Part) -> Result<Option<String>> {
+    Ok(match load_file(path)?.remove(key(part)) {
+        Some(Value::String(answer)) => Some(answer),
+        Some(other) => bail!("expected a string for `{}`, got `{}`", key(part), other),
+        None => None,
+    })
+}
+
+/// The recorded answer for a part, if there is one
+pub fn expected(dir: &Path, day: u8, part: Part) -> Result<Option<String>> {
+    expected_in(&path(dir, day), part)
+}
+
+pub fn check(dir: &Path, day: u8, part: Part, answer: &str) -> Result<Check> {
+    Ok(match expected(dir, day, part)? {
+        Some(expected) if expected == answer => Check::Correct,
+        Some(expected) => Check::Wrong { expected },
+        None => Check::Unknown,
+    })
+}
+
+/// Store `answer` as the expected answer for a part, keeping the other part as it is
+pub fn record(dir: &Path, day: u8, part: Part, answer: &str) -> Result<()> {
+    let mut table = load(dir, day)?;
+    table.insert(key(part).into(), Value::String(answer.into()));
+    fs::create_dir_all(dir)?;
+    let path = path(dir, day);
+    fs::write(&path, toml::to_string(&table)?).context(format!("writing `{}`", path.display()))
+}
+
+/// Line by line diff, with `-` for expected and `+` for actual lines
+pub fn diff(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut output = String::new();
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (Some(e), Some(a)) if e == a => output.push_str(&format!("  {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    output.push_str(&format!("- {}\n", e));
+                }
+                if let Some(a) = a {
+                    output.push_str(&format!("+ {}\n", a));
+                }
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_check() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        assert_eq!(check(&dir, 13, Part::Part1, "17")?, Check::Unknown);
+
+        record(&dir, 13, Part::Part1, "17")?;
+        record(&dir, 13, Part::Part2, "#####\n#   #\n#####\n")?;
+        assert_eq!(check(&dir, 13, Part::Part1, "17")?, Check::Correct);
+        assert_eq!(
+            check(&dir, 13, Part::Part1, "18")?,
+            Check::Wrong {
+                expected: "17".into()
+            }
+        );
+        assert_eq!(
+            check(&dir, 13, Part::Part2, "#####\n#   #\n#####\n")?,
+            Check::Correct
+        );
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("1\n2\n3", "1\n4\n3"), "  1\n- 2\n+ 4\n  3\n");
+        assert_eq!(diff("1", "1\n2"), "  1\n+ 2\n");
+    }
+}
@@ -1,61 +1,260 @@
-use anyhow::{Context, Error, Result};
-use std::fmt;
-use std::ops::{Add, AddAssign, Mul, Sub};
-use std::str::FromStr;
+use crate::point::Point;
 
-#[derive(PartialEq, Eq, Default, Clone, Copy, Hash)]
-pub struct Coor {
-    pub x: i64,
-    pub y: i64,
-}
+pub type Coor = Point<2>;
 
 impl Coor {
     pub const fn new(x: i64, y: i64) -> Self {
-        Coor { x, y }
+        Point([x, y])
     }
-}
-impl fmt::Debug for Coor {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+
+    pub const fn x(&self) -> i64 {
+        self.0[0]
     }
-}
 
-impl FromStr for Coor {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self> {
-        let (x, y) = s.split_once(',').context("No comma")?;
-        Ok(Coor::new(x.parse()?, y.parse()?))
+    pub const fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    /// The 4 coordinates sharing an edge with this one, in reading order
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Coor> {
+        [Direction::N, Direction::W, Direction::E, Direction::S]
+            .into_iter()
+            .map(move |d| self + d.into())
+    }
+
+    /// The 8 coordinates sharing an edge or a corner with this one, in reading order
+    pub fn all_neighbours(self) -> impl Iterator<Item = Coor> {
+        self.window(1).filter(move |&c| c != self)
+    }
+
+    /// The square of coordinates at most `radius` away on each axis, this one
+    /// included, in reading order
+    pub fn window(self, radius: i64) -> impl Iterator<Item = Coor> {
+        (-radius..=radius)
+            .flat_map(move |y| (-radius..=radius).map(move |x| self + Coor::new(x, y)))
     }
 }
 
-impl Add for Coor {
-    type Output = Self;
+/// A compass direction, with north being up, towards negative y
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// Clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// Clockwise from north
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
 
-    fn add(self, other: Self) -> Self {
-        Coor::new(self.x + other.x, self.y + other.y)
+    /// Turn clockwise by `eighths` of a full turn, anticlockwise if negative
+    pub fn turn(self, eighths: i32) -> Self {
+        Direction::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(2)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-2)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as i32 % 2 == 1
     }
 }
 
-impl AddAssign for Coor {
-    // type Output = Self;
+impl From<Direction> for Coor {
+    fn from(direction: Direction) -> Self {
+        use Direction::*;
+        match direction {
+            N => Coor::new(0, -1),
+            NE => Coor::new(1, -1),
+            E => Coor::new(1, 0),
+            SE => Coor::new(1, 1),
+            S => Coor::new(0, 1),
+            SW => Coor::new(-1, 1),
+            W => Coor::new(-1, 0),
+            NW => Coor::new(-1, -1),
+        }
+    }
+}
 
-    fn add_assign(&mut self, other: Self) {
-        // Coor::new(self.x + other.x, self.y + other.y)
-        *self = *self + other;
+impl From<(i64, i64)> for Coor {
+    fn from((x, y): (i64, i64)) -> Self {
+        Coor::new(x, y)
     }
 }
 
-impl Sub for Coor {
-    type Output = Self;
+/// The coordinates from `min` to `max` inclusive. Empty if `max` is below or left of `min`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Rect {
+    pub min: Coor,
+    pub max: Coor,
+}
+
+impl Rect {
+    pub const fn new(min: Coor, max: Coor) -> Self {
+        Rect { min, max }
+    }
 
-    fn sub(self, other: Self) -> Self {
-        self + other * -1
+    /// The smallest rectangle covering all of `points`, `None` if there aren't any
+    pub fn from_points<I: IntoIterator<Item = Coor>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), |rect, p| {
+            Rect::new(rect.min.component_min(p), rect.max.component_max(p))
+        }))
+    }
+
+    pub fn width(&self) -> i64 {
+        (self.max.x() - self.min.x() + 1).max(0)
+    }
+
+    pub fn height(&self) -> i64 {
+        (self.max.y() - self.min.y() + 1).max(0)
+    }
+
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, coor: Coor) -> bool {
+        (self.min.x()..=self.max.x()).contains(&coor.x())
+            && (self.min.y()..=self.max.y()).contains(&coor.y())
+    }
+
+    /// Grow by `by` on every side, or shrink if it's negative
+    pub fn expand(&self, by: i64) -> Self {
+        Rect::new(self.min - Coor::new(by, by), self.max + Coor::new(by, by))
+    }
+
+    /// The points in both, `None` if there aren't any
+    pub fn intersection(&self, other: &Rect) -> Option<Self> {
+        let rect = Rect::new(
+            self.min.component_max(other.min),
+            self.max.component_min(other.max),
+        );
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Every point inside, in reading order
+    pub fn points(&self) -> impl Iterator<Item = Coor> {
+        let Rect { min, max } = *self;
+        (min.y()..=max.y()).flat_map(move |y| (min.x()..=max.x()).map(move |x| Coor::new(x, y)))
     }
 }
 
-impl Mul<i64> for Coor {
-    type Output = Self;
-    fn mul(self, rhs: i64) -> Self::Output {
-        Coor::new(self.x * rhs, self.y * rhs)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::SW.turn(3), Direction::N);
+        assert_eq!(Direction::NE.turn(-3), Direction::W);
+        assert_eq!(Direction::E.reverse(), Direction::W);
+        assert!(Direction::NW.is_diagonal());
+        for d in Direction::ALL {
+            assert_eq!(Coor::from(d) + Coor::from(d.reverse()), Coor::new(0, 0));
+        }
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let c = Coor::new(5, 5);
+        assert_eq!(
+            c.orthogonal_neighbours().collect::<Vec<_>>(),
+            vec![
+                Coor::new(5, 4),
+                Coor::new(4, 5),
+                Coor::new(6, 5),
+                Coor::new(5, 6)
+            ]
+        );
+        assert_eq!(c.all_neighbours().count(), 8);
+        let window: Vec<_> = Coor::new(0, 0).window(1).collect();
+        assert_eq!(window.len(), 9);
+        assert_eq!(window[0], Coor::new(-1, -1));
+        assert_eq!(window[1], Coor::new(0, -1));
+        assert_eq!(window[4], Coor::new(0, 0));
+        assert_eq!(Coor::new(0, 0).window(2).count(), 25);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Coor::new(1, -2);
+        let b = Coor::new(-3, 1);
+        assert_eq!(a.manhattan_to(b), 7);
+        assert_eq!(b.manhattan_to(a), 7);
+        assert_eq!(a.chebyshev_to(b), 4);
+        assert_eq!(a.euclidean_squared_to(b), 25);
+    }
+
+    #[test]
+    fn test_rect() {
+        let points = [Coor::new(3, -1), Coor::new(0, 2), Coor::new(1, 1)];
+        let rect = Rect::from_points(points).unwrap();
+        assert_eq!(rect, Rect::new(Coor::new(0, -1), Coor::new(3, 2)));
+        assert_eq!(Rect::from_points([]), None);
+        assert_eq!((rect.width(), rect.height(), rect.area()), (4, 4, 16));
+        assert!(rect.contains(Coor::new(3, 2)));
+        assert!(!rect.contains(Coor::new(4, 2)));
+        assert_eq!(rect.expand(1).area(), 36);
+        assert_eq!(rect.expand(-2).area(), 0);
+
+        let other = Rect::new(Coor::new(2, 2), Coor::new(5, 5));
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect::new(Coor::new(2, 2), Coor::new(3, 2)))
+        );
+        assert_eq!(rect.intersection(&other.expand(-1)), None);
+
+        let small = Rect::new(Coor::new(0, 0), Coor::new(1, 1));
+        assert_eq!(
+            small.points().collect::<Vec<_>>(),
+            vec![
+                Coor::new(0, 0),
+                Coor::new(1, 0),
+                Coor::new(0, 1),
+                Coor::new(1, 1)
+            ]
+        );
+        assert_eq!(
+            Rect::new(Coor::new(1, 0), Coor::new(0, 0)).points().count(),
+            0
+        );
     }
 }
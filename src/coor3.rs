@@ -1,7 +1,6 @@
-use anyhow::{Context, Error, Result};
-use std::fmt;
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
-use std::str::FromStr;
+use crate::point::Point;
+use lazy_static::lazy_static;
+use std::ops::Mul;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Axis {
@@ -28,110 +27,524 @@ impl Axis {
     }
 }
 
-#[derive(PartialEq, Eq, Default, Clone, Copy, Hash)]
-pub struct Coor3 {
-    pub x: i64,
-    pub y: i64,
-    pub z: i64,
-}
+pub type Coor3 = Point<3>;
 
 impl Coor3 {
     pub const fn new(x: i64, y: i64, z: i64) -> Self {
-        Coor3 { x, y, z }
+        Point([x, y, z])
     }
 
-    pub fn axis(&self, axis: Axis) -> i64 {
-        match axis {
-            Axis::X => self.x,
-            Axis::Y => self.y,
-            Axis::Z => self.z,
-        }
+    pub const fn x(&self) -> i64 {
+        self.0[0]
     }
 
-    pub fn manhattan(&self) -> i64 {
-        self.x + self.y + self.z
+    pub const fn y(&self) -> i64 {
+        self.0[1]
     }
-}
-impl fmt::Debug for Coor3 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({},{},{})", self.x, self.y, self.z)
+
+    pub const fn z(&self) -> i64 {
+        self.0[2]
     }
-}
 
-impl FromStr for Coor3 {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self> {
-        let (x, yz) = s.split_once(',').context("No 1st comma")?;
-        let (y, z) = yz.split_once(',').context("No 2nd comma")?;
-        Ok(Coor3::new(x.parse()?, y.parse()?, z.parse()?))
+    pub fn axis(&self, axis: Axis) -> i64 {
+        match axis {
+            Axis::X => self.x(),
+            Axis::Y => self.y(),
+            Axis::Z => self.z(),
+        }
     }
 }
 
 impl From<(i64, i64, i64)> for Coor3 {
     fn from(tup: (i64, i64, i64)) -> Self {
         let (x, y, z) = tup;
-        Coor3 { x, y, z }
+        Coor3::new(x, y, z)
     }
 }
 
-impl Add for Coor3 {
-    type Output = Self;
+lazy_static! {
+    static ref ALL_ROTATIONS: Vec<Rotation> = Rotation::generate();
+}
 
-    fn add(self, other: Self) -> Self {
-        Coor3::new(self.x + other.x, self.y + other.y, self.z + other.z)
-    }
+/// A rotation by quarter turns, as a matrix of 0s and ±1s. `a * b` rotates by `b` then `a`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Rotation {
+    rows: [[i64; 3]; 3],
 }
 
-impl AddAssign for Coor3 {
-    fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        rows: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// A quarter turn anticlockwise looking down `axis` towards the origin
+    pub fn quarter_turn(axis: Axis) -> Self {
+        let rows = match axis {
+            Axis::X => [[1, 0, 0], [0, 0, -1], [0, 1, 0]],
+            Axis::Y => [[0, 0, 1], [0, 1, 0], [-1, 0, 0]],
+            Axis::Z => [[0, -1, 0], [1, 0, 0], [0, 0, 1]],
+        };
+        Rotation { rows }
     }
-}
 
-impl Sub for Coor3 {
-    type Output = Self;
+    /// The 24 ways of facing along an axis with another one up, starting with `IDENTITY`
+    pub fn all() -> &'static [Rotation] {
+        &ALL_ROTATIONS
+    }
+
+    /// Every signed permutation of the axes that doesn't mirror
+    fn generate() -> Vec<Rotation> {
+        let permutations = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        let mut all = vec![];
+        for permutation in permutations {
+            for signs in 0..8 {
+                let mut rows = [[0; 3]; 3];
+                for (i, row) in rows.iter_mut().enumerate() {
+                    row[permutation[i]] = if signs & (1 << i) == 0 { 1 } else { -1 };
+                }
+                let rotation = Rotation { rows };
+                if rotation.determinant() == 1 {
+                    all.push(rotation);
+                }
+            }
+        }
+        all
+    }
 
-    fn sub(self, other: Self) -> Self {
-        self + other * -1
+    fn determinant(&self) -> i64 {
+        let m = &self.rows;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Rotate by `first`, then by this one
+    pub fn compose(&self, first: Rotation) -> Self {
+        let mut rows = [[0; 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.rows[i][k] * first.rows[k][j]).sum();
+            }
+        }
+        Rotation { rows }
+    }
+
+    /// The rotation undoing this one, which for a rotation matrix is its transpose
+    pub fn inverse(&self) -> Self {
+        let mut rows = [[0; 3]; 3];
+        for (i, row) in rows.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = self.rows[j][i];
+            }
+        }
+        Rotation { rows }
+    }
+
+    pub fn apply(&self, coor: Coor3) -> Coor3 {
+        let [x, y, z] = self
+            .rows
+            .map(|row| row[0] * coor.x() + row[1] * coor.y() + row[2] * coor.z());
+        Coor3::new(x, y, z)
+    }
+
+    /// `apply`, or `None` if a component overflows
+    pub fn checked_apply(&self, coor: Coor3) -> Option<Coor3> {
+        let mut result = Coor3::default();
+        for (i, row) in self.rows.iter().enumerate() {
+            result[i] = row
+                .iter()
+                .zip(coor.0)
+                .try_fold(0i64, |sum, (m, c)| sum.checked_add(m.checked_mul(c)?))?;
+        }
+        Some(result)
     }
 }
 
-impl SubAssign for Coor3 {
-    fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
+impl Mul for Rotation {
+    type Output = Rotation;
+    fn mul(self, rhs: Rotation) -> Rotation {
+        self.compose(rhs)
     }
 }
 
-impl Neg for Coor3 {
-    type Output = Self;
+impl Mul<Coor3> for Rotation {
+    type Output = Coor3;
+    fn mul(self, rhs: Coor3) -> Coor3 {
+        self.apply(rhs)
+    }
+}
+
+/// A rotation followed by a translation, e.g. from one scanner's view to another's.
+/// `a * b` transforms by `b` then `a`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Transform {
+    pub rotation: Rotation,
+    pub translation: Coor3,
+}
 
-    fn neg(self) -> Self {
-        Coor3::new(-self.x, -self.y, -self.z)
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        rotation: Rotation::IDENTITY,
+        translation: Coor3::new(0, 0, 0),
+    };
+
+    pub fn new(rotation: Rotation, translation: Coor3) -> Self {
+        Transform {
+            rotation,
+            translation,
+        }
+    }
+
+    /// Transform by `first`, then by this one
+    pub fn compose(&self, first: Transform) -> Self {
+        Transform {
+            rotation: self.rotation * first.rotation,
+            translation: self.apply(first.translation),
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        Transform {
+            rotation,
+            translation: -(rotation * self.translation),
+        }
+    }
+
+    pub fn apply(&self, coor: Coor3) -> Coor3 {
+        self.rotation * coor + self.translation
+    }
+
+    /// `compose`, or `None` if the translation overflows
+    pub fn checked_compose(&self, first: Transform) -> Option<Self> {
+        Some(Transform {
+            rotation: self.rotation * first.rotation,
+            translation: self.checked_apply(first.translation)?,
+        })
+    }
+
+    /// `apply`, or `None` if a component overflows
+    pub fn checked_apply(&self, coor: Coor3) -> Option<Coor3> {
+        self.rotation
+            .checked_apply(coor)?
+            .checked_add(self.translation)
     }
 }
 
-impl Mul<i64> for Coor3 {
-    type Output = Self;
-    fn mul(self, rhs: i64) -> Self::Output {
-        Coor3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+impl Mul for Transform {
+    type Output = Transform;
+    fn mul(self, rhs: Transform) -> Transform {
+        self.compose(rhs)
     }
 }
 
-impl Mul<Coor3> for i64 {
+impl Mul<Coor3> for Transform {
     type Output = Coor3;
-    fn mul(self, rhs: Coor3) -> Self::Output {
-        rhs * self
+    fn mul(self, rhs: Coor3) -> Coor3 {
+        self.apply(rhs)
     }
 }
 
-impl PartialOrd for Coor3 {
-    fn partial_cmp(&self, other: &Coor3) -> Option<std::cmp::Ordering> {
-        (self.x, self.y, self.x).partial_cmp(&(other.x, other.y, other.z))
+/// The points from `min` to `max` inclusive. Empty if `max` is below `min` on any axis
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Cuboid {
+    pub min: Coor3,
+    pub max: Coor3,
+}
+
+impl Cuboid {
+    pub const fn new(min: Coor3, max: Coor3) -> Self {
+        Cuboid { min, max }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x() > self.max.x() || self.min.y() > self.max.y() || self.min.z() > self.max.z()
     }
+
+    pub fn volume(&self) -> i64 {
+        if self.is_empty() {
+            return 0;
+        }
+        let size = self.max - self.min + Coor3::new(1, 1, 1);
+        size.x() * size.y() * size.z()
+    }
+
+    /// `volume`, or `None` if it doesn't fit in an `i64`
+    pub fn checked_volume(&self) -> Option<i64> {
+        if self.is_empty() {
+            return Some(0);
+        }
+        let size = self
+            .max
+            .checked_sub(self.min)?
+            .checked_add(Coor3::new(1, 1, 1))?;
+        size.x().checked_mul(size.y())?.checked_mul(size.z())
+    }
+
+    pub fn contains(&self, coor: Coor3) -> bool {
+        (self.min.x()..=self.max.x()).contains(&coor.x())
+            && (self.min.y()..=self.max.y()).contains(&coor.y())
+            && (self.min.z()..=self.max.z()).contains(&coor.z())
+    }
+
+    /// The points in both, `None` if there aren't any
+    pub fn intersection(&self, other: &Cuboid) -> Option<Self> {
+        let cuboid = Cuboid::new(
+            self.min.component_max(other.min),
+            self.max.component_min(other.max),
+        );
+        if cuboid.is_empty() {
+            None
+        } else {
+            Some(cuboid)
+        }
+    }
+
+    /// The points not in `other`, as at most 6 disjoint cuboids: whole slabs either side
+    /// along x, then the rest of the slab either side along y, then along z
+    pub fn subtract(&self, other: &Cuboid) -> Vec<Cuboid> {
+        let cut = match self.intersection(other) {
+            Some(cut) => cut,
+            None => return vec![*self],
+        };
+        let (min, max) = (self.min, self.max);
+        [
+            Cuboid::new(min, Coor3::new(cut.min.x() - 1, max.y(), max.z())),
+            Cuboid::new(Coor3::new(cut.max.x() + 1, min.y(), min.z()), max),
+            Cuboid::new(
+                Coor3::new(cut.min.x(), min.y(), min.z()),
+                Coor3::new(cut.max.x(), cut.min.y() - 1, max.z()),
+            ),
+            Cuboid::new(
+                Coor3::new(cut.min.x(), cut.max.y() + 1, min.z()),
+                Coor3::new(cut.max.x(), max.y(), max.z()),
+            ),
+            Cuboid::new(
+                Coor3::new(cut.min.x(), cut.min.y(), min.z()),
+                Coor3::new(cut.max.x(), cut.max.y(), cut.min.z() - 1),
+            ),
+            Cuboid::new(
+                Coor3::new(cut.min.x(), cut.min.y(), cut.max.z() + 1),
+                Coor3::new(cut.max.x(), cut.max.y(), max.z()),
+            ),
+        ]
+        .into_iter()
+        .filter(|c| !c.is_empty())
+        .collect()
+    }
+}
+
+/// A union of cuboids, kept as disjoint pieces so that volumes just add up
+#[derive(Debug, Default, Clone)]
+pub struct CuboidSet {
+    cuboids: Vec<Cuboid>,
 }
 
-impl Ord for Coor3 {
-    fn cmp(&self, other: &Coor3) -> std::cmp::Ordering {
-        (self.x, self.y, self.x).cmp(&(other.x, other.y, other.z))
+impl CuboidSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, cuboid: Cuboid) {
+        self.remove(&cuboid);
+        if !cuboid.is_empty() {
+            self.cuboids.push(cuboid);
+        }
+    }
+
+    pub fn remove(&mut self, cuboid: &Cuboid) {
+        self.cuboids = self
+            .cuboids
+            .iter()
+            .flat_map(|c| c.subtract(cuboid))
+            .collect();
+    }
+
+    pub fn contains(&self, coor: Coor3) -> bool {
+        self.cuboids.iter().any(|c| c.contains(coor))
+    }
+
+    pub fn volume(&self) -> i64 {
+        self.cuboids.iter().map(Cuboid::volume).sum()
+    }
+
+    /// `volume`, or `None` if it doesn't fit in an `i64`
+    pub fn checked_volume(&self) -> Option<i64> {
+        self.cuboids
+            .iter()
+            .try_fold(0i64, |sum, c| sum.checked_add(c.checked_volume()?))
+    }
+
+    /// The disjoint pieces
+    pub fn cuboids(&self) -> &[Cuboid] {
+        &self.cuboids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    fn coor3() -> impl Strategy<Value = Coor3> {
+        // a small range so that equal components come up often
+        (-3i64..3, -3i64..3, -3i64..3).prop_map(Coor3::from)
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Coor3::new(1, -2, 3);
+        let b = Coor3::new(-3, 2, 4);
+        assert_eq!(Coor3::new(-1, 2, -3).manhattan(), 6);
+        assert_eq!(a.manhattan_to(b), 9);
+        assert_eq!(b.manhattan_to(a), 9);
+        assert_eq!(a.chebyshev_to(b), 4);
+        assert_eq!(a.euclidean_squared_to(b), 33);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let all = Rotation::all();
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 24);
+        assert_eq!(all[0], Rotation::IDENTITY);
+        for &a in all {
+            assert_eq!(a * a.inverse(), Rotation::IDENTITY);
+            for &b in all {
+                assert!(all.contains(&(a * b)));
+            }
+        }
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let turn = Rotation::quarter_turn(axis);
+            assert!(all.contains(&turn));
+            assert_eq!(turn * turn * turn * turn, Rotation::IDENTITY);
+            assert_eq!(turn * axis.coor(), axis.coor());
+        }
+        let turn = Rotation::quarter_turn(Axis::Z);
+        assert_eq!(turn * Coor3::new(1, 2, 3), Coor3::new(-2, 1, 3));
+        let x_then_z = Rotation::quarter_turn(Axis::Z) * Rotation::quarter_turn(Axis::X);
+        // x goes nowhere, then to y
+        assert_eq!(x_then_z * Coor3::new(1, 0, 0), Coor3::new(0, 1, 0));
+    }
+
+    #[test]
+    fn test_transform() {
+        let a = Transform::new(Rotation::all()[5], Coor3::new(1, -2, 3));
+        let b = Transform::new(Rotation::all()[17], Coor3::new(-7, 0, 4));
+        let coor = Coor3::new(4, 5, -6);
+        assert_eq!((a * b) * coor, a * (b * coor));
+        assert_eq!(a.inverse() * (a * coor), coor);
+        assert_eq!(a * a.inverse(), Transform::IDENTITY);
+        assert_eq!(Transform::IDENTITY * coor, coor);
+        assert_eq!(a.checked_compose(b), Some(a * b));
+        assert_eq!(a.checked_apply(coor), Some(a * coor));
+        let min = Coor3::new(i64::MIN, 0, 0);
+        assert_eq!(Rotation::quarter_turn(Axis::Y).checked_apply(min), None);
+        let step = Transform::new(Rotation::IDENTITY, Coor3::new(1, 0, 0));
+        assert_eq!(step.checked_apply(-(min + step.translation)), None);
+    }
+
+    fn cuboid(min: (i64, i64, i64), max: (i64, i64, i64)) -> Cuboid {
+        Cuboid::new(min.into(), max.into())
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = cuboid((1, 1, 1), (3, 3, 3));
+        assert_eq!(a.volume(), 27);
+        assert!(a.contains(Coor3::new(3, 1, 2)));
+        assert!(!a.contains(Coor3::new(4, 1, 2)));
+        assert_eq!(cuboid((1, 0, 0), (0, 0, 0)).volume(), 0);
+        assert_eq!(a.checked_volume(), Some(27));
+        let huge = 1 << 30;
+        assert_eq!(cuboid((0, 0, 0), (huge, huge, huge)).checked_volume(), None);
+
+        let b = cuboid((3, 3, 0), (5, 5, 9));
+        assert_eq!(a.intersection(&b), Some(cuboid((3, 3, 1), (3, 3, 3))));
+        // touching counts, as the ends are inclusive
+        assert!(cuboid((1, 0, 0), (3, 0, 0))
+            .intersection(&cuboid((3, 0, 0), (5, 0, 0)))
+            .is_some());
+        assert_eq!(a.intersection(&cuboid((4, 1, 1), (5, 3, 3))), None);
+    }
+
+    #[test]
+    fn test_subtract() {
+        let a = cuboid((1, 1, 1), (3, 3, 3));
+        let centre = cuboid((2, 2, 2), (2, 2, 2));
+        let pieces = a.subtract(&centre);
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<i64>(), 26);
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(p.intersection(&centre).is_none());
+            for q in &pieces[i + 1..] {
+                assert!(p.intersection(q).is_none());
+            }
+        }
+        assert_eq!(a.subtract(&a), vec![]);
+        assert_eq!(a.subtract(&cuboid((5, 5, 5), (6, 6, 6))), vec![a]);
+        assert_eq!(
+            a.subtract(&cuboid((3, 0, 0), (9, 9, 9))),
+            vec![cuboid((1, 1, 1), (2, 3, 3))]
+        );
+    }
+
+    #[test]
+    fn test_cuboid_set() {
+        let mut set = CuboidSet::new();
+        set.add(cuboid((10, 10, 10), (12, 12, 12)));
+        set.add(cuboid((11, 11, 11), (13, 13, 13)));
+        assert_eq!(set.volume(), 27 + 19);
+        set.remove(&cuboid((9, 9, 9), (11, 11, 11)));
+        assert_eq!(set.volume(), 38);
+        set.add(cuboid((10, 10, 10), (10, 10, 10)));
+        assert_eq!(set.volume(), 39);
+        assert_eq!(set.checked_volume(), Some(39));
+        assert!(set.contains(Coor3::new(13, 13, 13)));
+        assert!(!set.contains(Coor3::new(11, 11, 11)));
+        let pieces = set.cuboids();
+        for (i, p) in pieces.iter().enumerate() {
+            assert!(pieces[i + 1..].iter().all(|q| p.intersection(q).is_none()));
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(Coor3::new(0, 0, 1) < Coor3::new(0, 0, 2));
+        assert!(Coor3::new(0, 1, 0) > Coor3::new(0, 0, 9));
+        assert!(Coor3::new(1, 0, 0) > Coor3::new(0, 9, 9));
+    }
+
+    proptest! {
+        #[test]
+        fn ord_is_antisymmetric(a in coor3(), b in coor3()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+
+        #[test]
+        fn ord_agrees_with_eq(a in coor3(), b in coor3()) {
+            prop_assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+            prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        }
+
+        #[test]
+        fn ord_is_transitive(a in coor3(), b in coor3(), c in coor3()) {
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
+
+        #[test]
+        fn ord_is_lexicographic(a in coor3(), b in coor3()) {
+            prop_assert_eq!(a.cmp(&b), (a.x(), a.y(), a.z()).cmp(&(b.x(), b.y(), b.z())));
+        }
     }
 }
@@ -1,13 +1,16 @@
-use anyhow::{bail, Context, Error, Result};
+use crate::input::At;
+use anyhow::{anyhow, bail, Context, Error, Result};
 use std::collections::{HashMap, VecDeque};
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Eq)]
-enum Mode {
-    Position,
-    Immediate,
-    Relative,
+/// How an instruction's parameter is read or written
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Position = 0,
+    Immediate = 1,
+    Relative = 2,
 }
 
 impl TryFrom<i64> for Mode {
@@ -49,8 +52,8 @@ impl Modes {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Op {
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
     Add(Mode, Mode, Mode),
     Mul(Mode, Mode, Mode),
     Input(Mode),
@@ -84,12 +87,349 @@ impl TryFrom<i64> for Op {
             8 => Equals(modes.get()?, modes.get()?, modes.get()?),
             9 => AdjustRelativeBase(modes.get()?),
             99 => Halt,
-            _ => bail!("Invalid op code"),
+            _ => bail!("Invalid op code {}", op_value),
         };
         Ok(op)
     }
 }
 
+impl Op {
+    pub fn mnemonic(&self) -> &'static str {
+        use Op::*;
+        match self {
+            Add(..) => "ADD",
+            Mul(..) => "MUL",
+            Input(..) => "IN",
+            Output(..) => "OUT",
+            JumpIfTrue(..) => "JNZ",
+            JumpIfFalse(..) => "JZ",
+            LessThan(..) => "LT",
+            Equals(..) => "EQ",
+            AdjustRelativeBase(..) => "ARB",
+            Halt => "HLT",
+        }
+    }
+
+    /// The mode of each parameter, in order
+    pub fn modes(&self) -> Vec<Mode> {
+        use Op::*;
+        match *self {
+            Add(a, b, c) | Mul(a, b, c) | LessThan(a, b, c) | Equals(a, b, c) => vec![a, b, c],
+            JumpIfTrue(a, b) | JumpIfFalse(a, b) => vec![a, b],
+            Input(a) | Output(a) | AdjustRelativeBase(a) => vec![a],
+            Halt => vec![],
+        }
+    }
+
+    /// Whether the last parameter is where the result goes
+    pub fn writes(&self) -> bool {
+        use Op::*;
+        matches!(
+            self,
+            Add(..) | Mul(..) | Input(..) | LessThan(..) | Equals(..)
+        )
+    }
+
+    /// The value `Op::try_from` decodes to this op
+    pub fn opcode(&self) -> i64 {
+        use Op::*;
+        let base = match self {
+            Add(..) => 1,
+            Mul(..) => 2,
+            Input(..) => 3,
+            Output(..) => 4,
+            JumpIfTrue(..) => 5,
+            JumpIfFalse(..) => 6,
+            LessThan(..) => 7,
+            Equals(..) => 8,
+            AdjustRelativeBase(..) => 9,
+            Halt => 99,
+        };
+        mode_digits(&self.modes()) + base
+    }
+}
+
+/// The part of an opcode that gives the parameters' modes
+fn mode_digits(modes: &[Mode]) -> i64 {
+    modes
+        .iter()
+        .rev()
+        .fold(0, |digits, mode| digits * 10 + *mode as i64)
+        * 100
+}
+
+/// A parameter as written in assembly: `#5` is immediate, `[5]` position and `[rb+5]`
+/// relative
+struct Operand(Mode, i64);
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand(Mode::Immediate, value) => write!(f, "#{}", value),
+            Operand(Mode::Position, value) => write!(f, "[{}]", value),
+            Operand(Mode::Relative, value) => write!(f, "[rb{:+}]", value),
+        }
+    }
+}
+
+/// One decoded piece of a program
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Instruction {
+    /// An op at `address` reached as code, with its raw parameters
+    Op {
+        address: usize,
+        op: Op,
+        params: Vec<i64>,
+    },
+    /// A value not reached as code
+    Data { address: usize, value: i64 },
+}
+
+impl Instruction {
+    pub fn address(&self) -> usize {
+        match self {
+            Instruction::Op { address, .. } | Instruction::Data { address, .. } => *address,
+        }
+    }
+
+    /// How many values of the program this takes up
+    pub fn size(&self) -> usize {
+        match self {
+            Instruction::Op { params, .. } => params.len() + 1,
+            Instruction::Data { .. } => 1,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (op, params) = match self {
+            Instruction::Op { op, params, .. } => (op, params),
+            Instruction::Data { value, .. } => return write!(f, "DATA {}", value),
+        };
+        write!(f, "{}", op.mnemonic())?;
+        let mut operands: Vec<_> = op
+            .modes()
+            .into_iter()
+            .zip(params)
+            .map(|(mode, &value)| Operand(mode, value))
+            .collect();
+        let written = match op.writes() {
+            true => operands.pop(),
+            false => None,
+        };
+        for (i, operand) in operands.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " " } else { ", " }, operand)?;
+        }
+        if let Some(written) = written {
+            write!(f, " -> {}", written)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode the op at `address`, if it is one and fits in the program
+fn decode(program: &[i64], address: usize) -> Option<Instruction> {
+    let op = Op::try_from(program[address]).ok()?;
+    // stray mode digits wouldn't survive reassembly, so that's not code
+    if op.opcode() != program[address] {
+        return None;
+    }
+    let params = program.get(address + 1..address + 1 + op.modes().len())?;
+    Some(Instruction::Op {
+        address,
+        op,
+        params: params.to_vec(),
+    })
+}
+
+/// Decode the code reachable from the start of `program`, following jumps to immediate
+/// targets, and treat everything else as data
+pub fn disassemble(program: &[i64]) -> Vec<Instruction> {
+    let mut decoded: Vec<Option<Instruction>> = vec![None; program.len()];
+    let mut covered = vec![false; program.len()];
+    let mut todo = vec![0];
+    while let Some(address) = todo.pop() {
+        if address >= program.len() || covered[address] {
+            continue;
+        }
+        let instruction = match decode(program, address) {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+        let cells = address..address + instruction.size();
+        // jumping into the middle of another instruction
+        if covered[cells.clone()].iter().any(|&c| c) {
+            continue;
+        }
+        if let Instruction::Op { op, params, .. } = &instruction {
+            use Op::*;
+            // a constant condition only ever goes one way
+            let (falls_through, jumps) = match op {
+                Halt => (false, false),
+                JumpIfTrue(Mode::Immediate, _) => (params[0] == 0, params[0] != 0),
+                JumpIfFalse(Mode::Immediate, _) => (params[0] != 0, params[0] == 0),
+                JumpIfTrue(..) | JumpIfFalse(..) => (true, true),
+                _ => (true, false),
+            };
+            if falls_through {
+                todo.push(cells.end);
+            }
+            if let (true, Some(Mode::Immediate)) = (jumps, op.modes().get(1)) {
+                if let Ok(target) = usize::try_from(params[1]) {
+                    todo.push(target);
+                }
+            }
+        }
+        covered[cells].iter_mut().for_each(|c| *c = true);
+        decoded[address] = Some(instruction);
+    }
+
+    let mut instructions = vec![];
+    let mut address = 0;
+    while address < program.len() {
+        let instruction = decoded[address].take().unwrap_or(Instruction::Data {
+            address,
+            value: program[address],
+        });
+        address += instruction.size();
+        instructions.push(instruction);
+    }
+    instructions
+}
+
+/// A parameter or data value in assembly: a number, or the address of a label
+enum Value<'a> {
+    Number(i64),
+    Label(&'a str),
+}
+
+fn is_label(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && s != "rb"
+}
+
+fn parse_value(s: &str) -> Result<Value<'_>> {
+    let s = s.trim();
+    if let Ok(number) = s.parse() {
+        return Ok(Value::Number(number));
+    }
+    if !is_label(s) {
+        return Err(anyhow!("expected a number or label")).at(s);
+    }
+    Ok(Value::Label(s))
+}
+
+fn parse_operand(s: &str) -> Result<(Mode, Value<'_>)> {
+    let s = s.trim();
+    if let Some(value) = s.strip_prefix('#') {
+        return Ok((Mode::Immediate, parse_value(value)?));
+    }
+    let inner = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .context("expected `#value`, `[value]` or `[rb+value]`")
+        .at(s)?
+        .trim();
+    let offset = match inner.strip_prefix("rb") {
+        Some(offset) if !is_label(inner) => offset.trim(),
+        _ => return Ok((Mode::Position, parse_value(inner)?)),
+    };
+    let value = match offset.strip_prefix('+') {
+        Some(value) => parse_value(value)?,
+        None if offset.is_empty() => Value::Number(0),
+        None => Value::Number(
+            offset
+                .parse()
+                .context("expected `rb+value` or `rb-number`")
+                .at(offset)?,
+        ),
+    };
+    Ok((Mode::Relative, value))
+}
+
+/// Compile assembly as written by `disassemble`, one instruction per line, into a program.
+/// A line can start with a `label:` to use as a value elsewhere, `DATA` puts its comma
+/// separated values in as they are, and `;` starts a comment
+pub fn assemble(source: &str) -> Result<Vec<i64>> {
+    let mut labels = HashMap::new();
+    // each value of the program, with where it came from for errors
+    let mut values: Vec<(Value, &str)> = vec![];
+    for line in source.lines() {
+        let mut line = match line.split_once(';') {
+            Some((code, _comment)) => code,
+            None => line,
+        }
+        .trim();
+        if let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(anyhow!("invalid label")).at(label);
+            }
+            if labels.insert(label, values.len()).is_some() {
+                return Err(anyhow!("label defined twice")).at(label);
+            }
+            line = rest.trim();
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let (mnemonic, operands) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        if mnemonic.eq_ignore_ascii_case("DATA") {
+            for raw in operands.split(',') {
+                values.push((parse_value(raw)?, raw));
+            }
+            continue;
+        }
+
+        // with every mode position, so its opcode is just the op's own digits
+        let op = [1, 2, 3, 4, 5, 6, 7, 8, 9, 99]
+            .into_iter()
+            .filter_map(|opcode| Op::try_from(opcode).ok())
+            .find(|op| op.mnemonic().eq_ignore_ascii_case(mnemonic))
+            .context("unknown instruction")
+            .at(mnemonic)?;
+        let (reads, written) = match operands.split_once("->") {
+            Some((reads, written)) => (reads, Some(written)),
+            None => (operands, None),
+        };
+        if written.is_some() != op.writes() {
+            let expected = if op.writes() { "needs" } else { "doesn't take" };
+            return Err(anyhow!(
+                "`{}` {} a `->` destination",
+                op.mnemonic(),
+                expected
+            ))
+            .at(line);
+        }
+        let mut raw: Vec<&str> = match reads.trim() {
+            "" => vec![],
+            _ => reads.split(',').collect(),
+        };
+        raw.extend(written);
+        if raw.len() != op.modes().len() {
+            let expected = op.modes().len();
+            return Err(anyhow!("`{}` takes {} operands", op.mnemonic(), expected)).at(line);
+        }
+        let operands: Vec<_> = raw
+            .iter()
+            .map(|r| parse_operand(r))
+            .collect::<Result<_>>()?;
+        let modes: Vec<_> = operands.iter().map(|&(mode, _)| mode).collect();
+        values.push((Value::Number(mode_digits(&modes) + op.opcode()), mnemonic));
+        values.extend(operands.into_iter().map(|(_, value)| value).zip(raw));
+    }
+
+    values
+        .into_iter()
+        .map(|(value, raw)| match value {
+            Value::Number(number) => Ok(number),
+            Value::Label(label) => Ok(*labels.get(label).context("unknown label").at(raw)? as i64),
+        })
+        .collect()
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CpuState {
     Output(i64),
@@ -111,14 +451,14 @@ impl FromStr for Cpu {
     fn from_str(s: &str) -> Result<Self> {
         let program: Vec<_> = s
             .split(',')
-            .map(|x| x.parse::<i64>().context(format!("invalid op code `{}`", x)))
+            .map(|x| x.trim().parse::<i64>().context("invalid op code").at(x))
             .collect::<Result<_>>()?;
         Ok(Self::new(program))
     }
 }
 
 impl Cpu {
-    fn new(program: Vec<i64>) -> Self {
+    pub fn new(program: Vec<i64>) -> Self {
         Cpu {
             pc: 0,
             program,
@@ -132,12 +472,12 @@ impl Cpu {
         self.input.push_back(value);
     }
 
-    fn get(&self, mode: Mode, source: i64) -> i64 {
-        match mode {
+    fn get(&self, mode: Mode, source: i64) -> Result<i64> {
+        Ok(match mode {
             Mode::Immediate => source,
-            Mode::Position => self.get_mem(source),
-            Mode::Relative => self.get_mem(self.relative_base + source),
-        }
+            Mode::Position => self.get_mem(address(source)?),
+            Mode::Relative => self.get_mem(address(self.relative(source)?)?),
+        })
     }
 
     fn get_mem(&self, source: i64) -> i64 {
@@ -147,106 +487,118 @@ impl Cpu {
             .unwrap_or_else(|| self.memory.get(&source).unwrap_or(&0))
     }
 
-    fn set(&mut self, mode: Mode, destination: i64, value: i64) {
+    fn relative(&self, offset: i64) -> Result<i64> {
+        self.relative_base
+            .checked_add(offset)
+            .context("relative address overflowed")
+    }
+
+    /// The `n`th parameter of the current instruction
+    fn param(&self, n: usize) -> i64 {
+        self.get_mem((self.pc + n) as i64)
+    }
+
+    fn set(&mut self, mode: Mode, destination: i64, value: i64) -> Result<()> {
         let destination = match mode {
-            Mode::Immediate => unreachable!("set called with immediate mode"),
+            Mode::Immediate => bail!("write with immediate mode at {}", self.pc),
             Mode::Position => destination,
-            Mode::Relative => self.relative_base + destination,
+            Mode::Relative => self.relative(destination)?,
         };
-        assert!(destination >= 0);
+        let destination = address(destination)?;
         if destination as usize >= self.program.len() {
             self.memory.insert(destination, value);
         } else {
             self.program[destination as usize] = value;
         }
+        Ok(())
+    }
+
+    fn jump(&mut self, target: i64) -> Result<()> {
+        self.pc =
+            usize::try_from(target).context(format!("jump to negative address {}", target))?;
+        Ok(())
     }
 
     pub fn run(&mut self) -> Result<CpuState> {
+        self.run_for(usize::MAX)
+    }
+
+    /// Like `run`, but give up after executing `steps` instructions
+    pub fn run_for(&mut self, steps: usize) -> Result<CpuState> {
+        let mut executed = 0;
         let state = loop {
-            let op = Op::try_from(self.program[self.pc])?;
+            if executed == steps {
+                bail!("still running after {} steps", steps);
+            }
+            executed += 1;
+            let op = Op::try_from(self.param(0))?;
             use Op::*;
             match op {
                 Add(mode1, mode2, mode3) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    let c = self.program[self.pc + 3];
-                    self.set(mode3, c, self.get(mode1, a) + self.get(mode2, b));
+                    let (a, b, c) = (self.param(1), self.param(2), self.param(3));
+                    let sum = self
+                        .get(mode1, a)?
+                        .checked_add(self.get(mode2, b)?)
+                        .context(format!("addition overflowed at {}", self.pc))?;
+                    self.set(mode3, c, sum)?;
                     self.pc += 4;
                 }
                 Mul(mode1, mode2, mode3) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    let c = self.program[self.pc + 3];
-                    self.set(mode3, c, self.get(mode1, a) * self.get(mode2, b));
+                    let (a, b, c) = (self.param(1), self.param(2), self.param(3));
+                    let product = self
+                        .get(mode1, a)?
+                        .checked_mul(self.get(mode2, b)?)
+                        .context(format!("multiplication overflowed at {}", self.pc))?;
+                    self.set(mode3, c, product)?;
                     self.pc += 4;
                 }
                 Input(mode) => {
-                    let a = self.program[self.pc + 1];
+                    let a = self.param(1);
                     match self.input.pop_front() {
                         None => break CpuState::NeedsInput,
                         Some(value) => {
-                            self.set(mode, a, value);
+                            self.set(mode, a, value)?;
                             self.pc += 2;
                         }
                     }
                 }
                 Output(mode) => {
-                    let a = self.program[self.pc + 1];
-                    let value = self.get(mode, a);
+                    let a = self.param(1);
+                    let value = self.get(mode, a)?;
                     self.pc += 2;
                     break CpuState::Output(value);
                 }
                 JumpIfTrue(mode1, mode2) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    if self.get(mode1, a) != 0 {
-                        self.pc = self.get(mode2, b) as usize;
+                    let (a, b) = (self.param(1), self.param(2));
+                    if self.get(mode1, a)? != 0 {
+                        self.jump(self.get(mode2, b)?)?;
                     } else {
                         self.pc += 3;
                     }
                 }
                 JumpIfFalse(mode1, mode2) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    if self.get(mode1, a) == 0 {
-                        self.pc = self.get(mode2, b) as usize;
+                    let (a, b) = (self.param(1), self.param(2));
+                    if self.get(mode1, a)? == 0 {
+                        self.jump(self.get(mode2, b)?)?;
                     } else {
                         self.pc += 3;
                     }
                 }
                 LessThan(mode1, mode2, mode3) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    let c = self.program[self.pc + 3];
-                    self.set(
-                        mode3,
-                        c,
-                        if self.get(mode1, a) < self.get(mode2, b) {
-                            1
-                        } else {
-                            0
-                        },
-                    );
+                    let (a, b, c) = (self.param(1), self.param(2), self.param(3));
+                    let less = self.get(mode1, a)? < self.get(mode2, b)?;
+                    self.set(mode3, c, if less { 1 } else { 0 })?;
                     self.pc += 4;
                 }
                 Equals(mode1, mode2, mode3) => {
-                    let a = self.program[self.pc + 1];
-                    let b = self.program[self.pc + 2];
-                    let c = self.program[self.pc + 3];
-                    self.set(
-                        mode3,
-                        c,
-                        if self.get(mode1, a) == self.get(mode2, b) {
-                            1
-                        } else {
-                            0
-                        },
-                    );
+                    let (a, b, c) = (self.param(1), self.param(2), self.param(3));
+                    let equal = self.get(mode1, a)? == self.get(mode2, b)?;
+                    self.set(mode3, c, if equal { 1 } else { 0 })?;
                     self.pc += 4;
                 }
                 AdjustRelativeBase(mode) => {
-                    let a = self.program[self.pc + 1];
-                    self.relative_base += self.get(mode, a);
+                    let a = self.param(1);
+                    self.relative_base = self.relative(self.get(mode, a)?)?;
                     self.pc += 2;
                 }
 
@@ -257,17 +609,30 @@ impl Cpu {
     }
 }
 
+fn address(value: i64) -> Result<i64> {
+    if value < 0 {
+        bail!("negative address {}", value);
+    }
+    Ok(value)
+}
+
 pub fn read_memory(cpu: &Cpu, position: usize) -> i64 {
     cpu.get_mem(position as i64)
 }
 
 pub fn set_memory(cpu: &mut Cpu, position: usize, value: i64) {
-    cpu.program[position] = value;
+    match cpu.program.get_mut(position) {
+        Some(cell) => *cell = value,
+        None => {
+            cpu.memory.insert(position as i64, value);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_op() -> Result<()> {
@@ -279,6 +644,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_disassemble() -> Result<()> {
+        let instructions = disassemble(&[21001, 10, 3, 4, 99]);
+        assert_eq!(
+            instructions[0],
+            Instruction::Op {
+                address: 0,
+                op: Op::Add(Mode::Position, Mode::Immediate, Mode::Relative),
+                params: vec![10, 3, 4],
+            }
+        );
+        assert_eq!(instructions[0].to_string(), "ADD [10], #3 -> [rb+4]");
+        assert_eq!(instructions[1].to_string(), "HLT");
+
+        // data skipped by a jump, and after the halt
+        let listing: Vec<_> = disassemble(&[1105, 1, 4, 7, 203, -2, 1006, 5, 0, 99, 10099])
+            .iter()
+            .map(|i| (i.address(), i.to_string()))
+            .collect();
+        assert_eq!(
+            listing,
+            [
+                (0, "JNZ #1, #4"),
+                (3, "DATA 7"),
+                (4, "IN -> [rb-2]"),
+                (6, "JZ [5], #0"),
+                (9, "HLT"),
+                (10, "DATA 10099"),
+            ]
+            .map(|(a, s)| (a, s.to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble() -> Result<()> {
+        let program = assemble(
+            "; counts down from 3, outputting each
+                ADD [count], #0 -> [rb+1]
+            loop: OUT [count]
+                add [count], #-1 -> [count]
+                JNZ [count], #loop
+                HLT
+            count: DATA 3
+            DATA loop, -2",
+        )?;
+        assert_eq!(
+            program,
+            [21001, 14, 0, 1, 4, 14, 1001, 14, -1, 14, 1005, 14, 4, 99, 3, 4, -2]
+        );
+        let mut cpu = Cpu::new(program);
+        for expected in [3, 2, 1] {
+            assert_eq!(cpu.run()?, CpuState::Output(expected));
+        }
+        assert_eq!(cpu.run()?, CpuState::Halted);
+
+        assert_eq!(assemble("IN -> [rb]\nOUT [rb-3]")?, [203, 0, 204, -3]);
+        assert!(assemble("NOP").is_err());
+        assert!(assemble("OUT #1, #2").is_err());
+        assert!(assemble("OUT -> [1]").is_err());
+        assert!(assemble("ADD #1, #2").is_err());
+        assert!(assemble("JZ #0, #nowhere").is_err());
+        assert!(assemble("a: HLT\na: HLT").is_err());
+        assert!(assemble("OUT 5").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() -> Result<()> {
+        // runs off the end into zeroed memory
+        assert!(Cpu::from_str("1,0,0")?.run().is_err());
+        assert!(Cpu::from_str("1101,1,1")?.run().is_err());
+        assert!(Cpu::from_str("1101,1,1,-1,99")?.run().is_err());
+        assert!(Cpu::from_str("11101,1,1,5,99")?.run().is_err());
+        assert!(Cpu::from_str("1105,1,-7")?.run().is_err());
+        assert!(Cpu::from_str("1102,9223372036854775807,2,0,99")?
+            .run()
+            .is_err());
+        assert!(Cpu::from_str("109,-1,204,0,99")?.run().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_for() -> Result<()> {
+        // jumps back to itself forever
+        let mut cpu = Cpu::from_str("1105,1,0")?;
+        assert!(cpu.run_for(1000).is_err());
+        let mut cpu = Cpu::from_str("104,7,99")?;
+        assert_eq!(cpu.run_for(1)?, CpuState::Output(7));
+        assert_eq!(cpu.run_for(1)?, CpuState::Halted);
+        Ok(())
+    }
+
     #[test]
     fn test_203() -> Result<()> {
         let mut cpu = Cpu::from_str("203,10,99")?;
@@ -288,4 +746,34 @@ mod tests {
         assert_eq!(read_memory(&cpu, 12), 1);
         Ok(())
     }
+
+    #[test]
+    fn test_assemble_raw() -> Result<()> {
+        // the hand written programs of the tests above
+        assert_eq!(assemble("start: JNZ #1, #start")?, [1105, 1, 0]);
+        assert_eq!(assemble("OUT #7\nHLT")?, [104, 7, 99]);
+        assert_eq!(assemble("IN -> [rb+10]\nHLT")?, [203, 10, 99]);
+        Ok(())
+    }
+
+    /// Opcodes with any modes, small values that make jump targets inside the program,
+    /// and anything else
+    fn value() -> impl Strategy<Value = i64> {
+        let opcode = (
+            0i64..3,
+            0i64..3,
+            0i64..3,
+            prop::sample::select(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 99]),
+        )
+            .prop_map(|(a, b, c, op)| ((c * 10 + b) * 10 + a) * 100 + op);
+        prop_oneof![opcode, -2i64..40, any::<i64>()]
+    }
+
+    proptest! {
+        #[test]
+        fn disassembly_reassembles(program in prop::collection::vec(value(), 0..40)) {
+            let listing: Vec<_> = disassemble(&program).iter().map(|i| i.to_string()).collect();
+            prop_assert_eq!(assemble(&listing.join("\n")).unwrap(), program);
+        }
+    }
 }
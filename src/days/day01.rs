@@ -0,0 +1,65 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::Result;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        input.trim().lines().map(|x| x.parse().at(x)).collect()
+    }
+
+    fn part1(depths: &Vec<i32>) -> Result<usize> {
+        Ok(depths.iter().tuple_windows().filter(|(a, b)| a < b).count())
+    }
+
+    fn part2(depths: &Vec<i32>) -> Result<usize> {
+        Ok(depths
+            .iter()
+            .tuple_windows()
+            .map(|(&a, &b, &c)| a as i64 + b as i64 + c as i64)
+            .tuple_windows()
+            .filter(|(a, b)| a < b)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "1721
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day01::part1(&Day01::parse(TEST_INPUT)?)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1_empty() -> Result<()> {
+        assert_eq!(Day01::part1(&Day01::parse("")?)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day01::part2(&Day01::parse(TEST_INPUT)?)?, 5);
+        Ok(())
+    }
+}
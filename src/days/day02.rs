@@ -0,0 +1,148 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{bail, Context, Error, Result};
+
+#[derive(Debug, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Forward,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Command {
+    direction: Direction,
+    distance: i32,
+}
+
+impl Command {
+    fn new(direction: Direction, distance: i32) -> Self {
+        Command {
+            direction,
+            distance,
+        }
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        match s {
+            "forward" => Ok(Direction::Forward),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            _ => bail!("invalid direction `{}`", s),
+        }
+    }
+}
+
+impl TryFrom<&str> for Command {
+    type Error = Error;
+    fn try_from(s: &str) -> Result<Self> {
+        let (direction_raw, distance_raw) = s.split_once(' ').context("no space found").at(s)?;
+        Ok(Command::new(
+            Direction::try_from(direction_raw).at(direction_raw)?,
+            distance_raw
+                .parse()
+                .context("invalid distance")
+                .at(distance_raw)?,
+        ))
+    }
+}
+
+pub struct Day02;
+
+const TOO_FAR: &str = "the submarine went too far";
+
+fn add(a: i32, b: i32) -> Result<i32> {
+    a.checked_add(b).context(TOO_FAR)
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        input
+            .trim()
+            .split('\n')
+            .map(|line| Command::try_from(line).at(line))
+            .collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i32> {
+        use Direction::*;
+        let mut x = 0;
+        let mut y = 0;
+        for command in commands.iter() {
+            let n = command.distance;
+            match command.direction {
+                Forward => x = add(x, n)?,
+                Down => y = add(y, n)?,
+                Up => y = y.checked_sub(n).context(TOO_FAR)?,
+            }
+        }
+        x.checked_mul(y).context(TOO_FAR)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i32> {
+        use Direction::*;
+        let mut x = 0;
+        let mut y = 0;
+        let mut aim = 0;
+        for command in commands.iter() {
+            let n = command.distance;
+            match command.direction {
+                Down => aim = add(aim, n)?,
+                Up => aim = aim.checked_sub(n).context(TOO_FAR)?,
+                Forward => {
+                    x = add(x, n)?;
+                    y = add(y, aim.checked_mul(n).context(TOO_FAR)?)?;
+                }
+            }
+        }
+        x.checked_mul(y).context(TOO_FAR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        use Direction::*;
+        assert_eq!(
+            Day02::parse(TEST_INPUT)?,
+            vec![
+                Command::new(Forward, 5),
+                Command::new(Down, 5),
+                Command::new(Forward, 8),
+                Command::new(Up, 3),
+                Command::new(Down, 8),
+                Command::new(Forward, 2),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day02::part1(&Day02::parse(TEST_INPUT)?)?, 150);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day02::part2(&Day02::parse(TEST_INPUT)?)?, 900);
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Error, Result};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day04;
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    size: usize,
+    numbers: HashMap<i32, (usize, usize)>,
+    row_counts: Vec<usize>,
+    column_counts: Vec<usize>,
+}
+
+impl FromStr for Board {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut numbers = HashMap::new();
+        let size = s.split('\n').count();
+        for (row_idx, raw_row) in s.split('\n').enumerate() {
+            let row: Vec<&str> = raw_row.split_whitespace().collect();
+            if row.len() != size {
+                return Err(anyhow!("expected {} numbers in a {0}x{0} board", size)).at(raw_row);
+            }
+            for (col_idx, raw_number) in row.into_iter().enumerate() {
+                let n = raw_number.parse().at(raw_number)?;
+                if numbers.insert(n, (row_idx, col_idx)).is_some() {
+                    return Err(anyhow!("{} is already on the board", n)).at(raw_number);
+                }
+            }
+        }
+        Ok(Board::new(size, numbers))
+    }
+}
+
+impl Board {
+    fn new(size: usize, numbers: HashMap<i32, (usize, usize)>) -> Self {
+        Board {
+            size,
+            numbers,
+            row_counts: vec![0; size],
+            column_counts: vec![0; size],
+        }
+    }
+
+    fn mark(&mut self, n: i32) -> bool {
+        if let Some((row_idx, col_idx)) = self.numbers.remove(&n) {
+            self.row_counts[row_idx] += 1;
+            self.column_counts[col_idx] += 1;
+
+            if self.row_counts[row_idx] == self.size || self.column_counts[col_idx] == self.size {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn score(&self, number: i32) -> Result<i32> {
+        self.numbers
+            .keys()
+            .try_fold(0i32, |sum, &n| sum.checked_add(n))
+            .and_then(|sum| sum.checked_mul(number))
+            .context("score too large")
+    }
+}
+
+impl Solution for Day04 {
+    type Input = (Vec<i32>, Vec<Board>);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<Board>)> {
+        let mut entries = input.trim().split("\n\n");
+        let raw_numbers = entries.next().context("no numbers found")?;
+        let numbers: Vec<i32> = raw_numbers
+            .split(',')
+            .map(|s| s.parse().context("invalid number").at(s))
+            .collect::<Result<_>>()?;
+        let boards = entries.map(|s| s.parse()).collect::<Result<_>>()?;
+
+        Ok((numbers, boards))
+    }
+
+    fn part1((numbers, boards): &(Vec<i32>, Vec<Board>)) -> Result<i32> {
+        let mut boards = boards.clone();
+        for &number in numbers {
+            for board in &mut boards {
+                if board.mark(number) {
+                    return board.score(number);
+                }
+            }
+        }
+        bail!("no winner")
+    }
+
+    fn part2((numbers, boards): &(Vec<i32>, Vec<Board>)) -> Result<i32> {
+        let mut boards = boards.clone();
+        let mut winner = None;
+        for &number in numbers {
+            let mut end = boards.len();
+            let mut board_idx = 0;
+            while board_idx < end {
+                if boards[board_idx].mark(number) {
+                    let board = boards.swap_remove(board_idx);
+                    end -= 1;
+                    winner = Some((board, number));
+                } else {
+                    board_idx += 1;
+                }
+            }
+        }
+        if let Some((board, number)) = winner {
+            return board.score(number);
+        }
+        bail!("no winner")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str =
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day04::part1(&Day04::parse(TEST_INPUT)?)?, 4512);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day04::part2(&Day04::parse(TEST_INPUT)?)?, 1924);
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::coor::Coor;
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Error, Result};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day05;
+
+/// Keeps the number of points on a line manageable
+const MAX_COORDINATE: i64 = 1_000_000;
+
+#[derive(Debug)]
+pub struct Line {
+    start: Coor,
+    end: Coor,
+}
+
+impl Line {
+    fn new(start: Coor, end: Coor) -> Self {
+        Self { start, end }
+    }
+
+    fn diff(&self) -> Coor {
+        self.end - self.start
+    }
+
+    fn walk(&self, points: &mut HashMap<Coor, usize>) {
+        let diff = self.diff();
+        let direction = Coor::new(diff.x().signum(), diff.y().signum());
+        let mut point = self.start;
+        while point != self.end {
+            *points.entry(point).or_insert(0) += 1;
+            point += direction;
+        }
+        *points.entry(point).or_insert(0) += 1;
+    }
+
+    fn hor_or_vert(&self) -> bool {
+        let diff = self.diff();
+        diff.x() == 0 || diff.y() == 0
+    }
+}
+
+impl FromStr for Line {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let (start, end) = s.split_once(" -> ").context("no arrow").at(s)?;
+        let line = Line::new(start.parse()?, end.parse()?);
+        for coor in [line.start, line.end] {
+            if coor.x().abs() > MAX_COORDINATE || coor.y().abs() > MAX_COORDINATE {
+                return Err(anyhow!("coordinate out of range")).at(s);
+            }
+        }
+        let diff = line.diff();
+        if !line.hor_or_vert() && diff.x().abs() != diff.y().abs() {
+            return Err(anyhow!(
+                "lines have to be horizontal, vertical or at 45 degrees"
+            ))
+            .at(s);
+        }
+        Ok(line)
+    }
+}
+
+fn count_overlaps(points: &HashMap<Coor, usize>) -> usize {
+    points.values().filter(|&v| *v > 1).count()
+}
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        input
+            .trim()
+            .split('\n')
+            .map(|s| s.parse().at(s))
+            .collect::<Result<_>>()
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<usize> {
+        let mut points = HashMap::new();
+        lines
+            .iter()
+            .filter(|l| l.hor_or_vert())
+            .for_each(|l| l.walk(&mut points));
+        Ok(count_overlaps(&points))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<usize> {
+        let mut points = HashMap::new();
+        lines.iter().for_each(|line| line.walk(&mut points));
+        Ok(count_overlaps(&points))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day05::part1(&Day05::parse(TEST_INPUT)?)?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day05::part2(&Day05::parse(TEST_INPUT)?)?, 12);
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+
+pub struct Day06;
+
+fn run(initial: &[usize], rounds: usize) -> u64 {
+    let mut counts = [0; 9];
+    for &n in initial {
+        counts[n] += 1;
+    }
+    for _ in 0..rounds {
+        let zeros = counts[0];
+        for i in 0..8 {
+            counts[i] = counts[i + 1]
+        }
+        counts[6] += zeros;
+        counts[8] = zeros;
+    }
+    counts.iter().sum()
+}
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        input
+            .trim()
+            .split(',')
+            .map(|n| {
+                let timer = n.parse().context("invalid number").at(n)?;
+                if timer > 8 {
+                    return Err(anyhow!("timers go from 0 to 8")).at(n);
+                }
+                Ok(timer)
+            })
+            .collect()
+    }
+
+    fn part1(initial: &Vec<usize>) -> Result<u64> {
+        Ok(run(initial, 80))
+    }
+
+    fn part2(initial: &Vec<usize>) -> Result<u64> {
+        Ok(run(initial, 256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT)?)?, 5934);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT)?)?, 26984457539);
+        Ok(())
+    }
+}
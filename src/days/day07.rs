@@ -0,0 +1,67 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+
+pub struct Day07;
+
+/// Keeps the fuel for every crab together inside an `i64`
+const MAX_POSITION: i64 = 1_000_000;
+
+impl Solution for Day07 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        input
+            .trim()
+            .split(',')
+            .map(|n| {
+                let position = n.parse().context("invalid integer").at(n)?;
+                if !(0..=MAX_POSITION).contains(&position) {
+                    return Err(anyhow!("position out of range")).at(n);
+                }
+                Ok(position)
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<i64>) -> Result<i64> {
+        let mut numbers = numbers.clone();
+        numbers.sort_unstable();
+        let pos = numbers[numbers.len() / 2];
+        Ok(numbers.into_iter().map(|n| (n - pos).abs()).sum())
+    }
+
+    fn part2(numbers: &Vec<i64>) -> Result<i64> {
+        let mean = numbers.iter().sum::<i64>() / numbers.len() as i64;
+
+        let measure = |pos: i64| -> i64 {
+            numbers
+                .iter()
+                .map(|n| ((*n - pos).abs()) * ((*n - pos).abs() + 1) / 2)
+                .sum()
+        };
+
+        Ok(measure(mean).min(measure(mean + 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day07::part1(&Day07::parse(TEST_INPUT)?)?, 37);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day07::part2(&Day07::parse(TEST_INPUT)?)?, 168);
+        Ok(())
+    }
+}
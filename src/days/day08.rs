@@ -1,12 +1,11 @@
+use crate::input::At;
+use crate::Solution;
 use anyhow::{bail, Context, Error, Result};
-use aoc2021::dispatch;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct Day08;
 
 #[derive(Eq, Clone)]
 struct Digit {
@@ -75,7 +74,7 @@ impl FromStr for Digit {
 }
 
 #[derive(Debug, PartialEq)]
-struct Input {
+pub struct Input {
     patterns: Vec<Digit>,
     output: Vec<Digit>,
 }
@@ -84,8 +83,8 @@ impl FromStr for Input {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self> {
         let mut it = s.split(" | ");
-        let raw_patterns = it.next().context("no patterns")?;
-        let raw_output = it.next().context("no output")?;
+        let raw_patterns = it.next().context("no patterns").at(s)?;
+        let raw_output = it.next().context("no output").at(s)?;
         Ok(Input {
             patterns: raw_patterns
                 .split(' ')
@@ -122,28 +121,40 @@ b    .  b    .  .    c  b    c  b    c
 */
 
 impl Input {
-    fn decode(self) -> Result<usize> {
+    fn decode(&self) -> Result<usize> {
         let mut by_size: HashMap<usize, Vec<Digit>> = HashMap::new();
-        for pattern in self.patterns.into_iter() {
+        for pattern in self.patterns.iter() {
             by_size
                 .entry(pattern.segments.len())
-                .or_insert_with(Vec::new)
-                .push(pattern);
+                .or_default()
+                .push(pattern.copy());
         }
 
-        let d1 = one(&by_size[&2])?;
-        let d4 = one(&by_size[&4])?;
-        let d7 = one(&by_size[&3])?;
-        let d8 = one(&by_size[&7])?;
+        let sized = |n| by_size.get(&n).map_or(&[][..], Vec::as_slice);
+
+        let d1 = one(sized(2)).context("digit 1")?;
+        let d4 = one(sized(4)).context("digit 4")?;
+        let d7 = one(sized(3)).context("digit 7")?;
+        let d8 = one(sized(7)).context("digit 8")?;
 
-        let six_segments = &by_size[&6];
-        assert_eq!(six_segments.len(), 3);
+        let six_segments = sized(6);
+        if six_segments.len() != 3 {
+            bail!(
+                "expected 3 patterns with 6 segments, got {}",
+                six_segments.len()
+            );
+        }
         let d6 = one(six_segments.iter().filter(|s| s.share(&d1, 1)))?;
         let d9 = one(six_segments.iter().filter(|s| s.share(&d4, 4)))?;
         let d0 = one(six_segments.iter().filter(|&s| s != &d6 && s != &d9))?;
 
-        let five_segments = &by_size[&5];
-        assert_eq!(five_segments.len(), 3);
+        let five_segments = sized(5);
+        if five_segments.len() != 3 {
+            bail!(
+                "expected 3 patterns with 5 segments, got {}",
+                five_segments.len()
+            );
+        }
         let d3 = one(five_segments.iter().filter(|s| s.share(&d1, 2)))?;
         let d5 = one(five_segments
             .iter()
@@ -164,25 +175,19 @@ impl Input {
             (d8, 8),
             (d9, 9),
         ]);
-        assert_eq!(map.len(), 10);
-
-        Ok(self.output.iter().fold(0, |acc, x| acc * 10 + map[x]))
-    }
-}
+        if map.len() != 10 {
+            bail!("patterns don't decode to 10 distinct digits");
+        }
 
-fn part1(input: &str) -> Result<usize> {
-    Ok(input
-        .lines()
-        .map(|l| l.parse::<Input>())
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .map(|i| {
-            i.output
-                .iter()
-                .filter(|o| matches!(o.segments.len(), 2 | 3 | 4 | 7))
-                .count()
+        self.output.iter().try_fold(0usize, |acc, x| {
+            let digit = map
+                .get(x)
+                .with_context(|| format!("unknown output {:?}", x))?;
+            acc.checked_mul(10)
+                .and_then(|acc| acc.checked_add(*digit))
+                .context("output too large")
         })
-        .sum())
+    }
 }
 
 fn one<'a, I: IntoIterator<Item = &'a Digit>>(values: I) -> Result<Digit> {
@@ -194,14 +199,32 @@ fn one<'a, I: IntoIterator<Item = &'a Digit>>(values: I) -> Result<Digit> {
     Ok(item.copy())
 }
 
-fn part2(input: &str) -> Result<usize> {
-    input
-        .lines()
-        .map(|l| l.parse::<Input>())
-        .collect::<Result<Vec<_>>>()?
-        .into_iter()
-        .map(Input::decode)
-        .sum()
+impl Solution for Day08 {
+    type Input = Vec<Input>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Input>> {
+        input.lines().map(|l| l.parse::<Input>().at(l)).collect()
+    }
+
+    fn part1(entries: &Vec<Input>) -> Result<usize> {
+        Ok(entries
+            .iter()
+            .map(|i| {
+                i.output
+                    .iter()
+                    .filter(|o| matches!(o.segments.len(), 2 | 3 | 4 | 7))
+                    .count()
+            })
+            .sum())
+    }
+
+    fn part2(entries: &Vec<Input>) -> Result<usize> {
+        entries.iter().try_fold(0usize, |sum, entry| {
+            sum.checked_add(entry.decode()?).context("sum too large")
+        })
+    }
 }
 
 #[cfg(test)]
@@ -222,7 +245,7 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 
     #[test]
     fn test_part1() -> Result<()> {
-        assert_eq!(part1(TEST_INPUT)?, 26);
+        assert_eq!(Day08::part1(&Day08::parse(TEST_INPUT)?)?, 26);
         Ok(())
     }
 
@@ -237,7 +260,7 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 
     #[test]
     fn test_part2() -> Result<()> {
-        assert_eq!(part2(TEST_INPUT)?, 61229);
+        assert_eq!(Day08::part2(&Day08::parse(TEST_INPUT)?)?, 61229);
         Ok(())
     }
 }
@@ -0,0 +1,150 @@
+use crate::coor::Coor;
+use crate::frames;
+use crate::grid::Grid;
+use crate::image::{self, Rgb};
+use crate::Solution;
+use anyhow::{bail, Context, Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub struct Day09;
+
+#[derive(Clone)]
+pub struct Map {
+    heights: Grid<i32>,
+    ends_in: HashMap<Coor, Coor>,
+}
+
+impl Map {
+    /// The lowest neighbour that isn't higher, the first in reading order on a tie so the
+    /// result doesn't depend on the order neighbours are visited in
+    fn flows_to(&self, coor: &Coor) -> Option<Coor> {
+        let height = self.heights[*coor];
+        self.heights
+            .orthogonal_neighbours(*coor)
+            .filter(|&dest| self.heights[dest] <= height)
+            .min_by_key(|&dest| (self.heights[dest], dest.y(), dest.x()))
+    }
+
+    fn follow(&mut self, coor: Coor) -> Result<Coor> {
+        let height = self.heights[coor];
+        if height == 9 {
+            return Ok(coor);
+        }
+        let mut seen = HashSet::new();
+        let mut prev = coor;
+        let mut maybe_next = Some(coor);
+        while let Some(next) = maybe_next {
+            if let Some(end) = self.ends_in.get(&next) {
+                return Ok(*end);
+            }
+            if !seen.insert(next) {
+                bail!("flowing from {:?} goes round in circles", coor);
+            }
+            prev = next;
+            maybe_next = self.flows_to(&next);
+        }
+        self.ends_in.insert(coor, prev);
+        Ok(prev)
+    }
+}
+
+/// A colour that's unlikely to match the neighbouring basins', from where the basin ends
+fn basin_colour(end: Coor) -> Rgb {
+    let hash = (end.x() as u64).wrapping_mul(2_654_435_761) ^ (end.y() as u64).wrapping_mul(40_503);
+    [
+        hash as u8 | 0x40,
+        (hash >> 8) as u8 | 0x40,
+        (hash >> 16) as u8 | 0x40,
+    ]
+}
+
+impl FromStr for Map {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let heights = Grid::parse_with(s, |c| Ok(c.to_digit(10).context("not a digit")? as i32))?;
+        Ok(Self {
+            heights,
+            ends_in: HashMap::new(),
+        })
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Map;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Map> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<i32> {
+        let mut sum = 0;
+        for (coor, height) in map.heights.iter() {
+            if map.flows_to(&coor).is_none() {
+                sum += height + 1;
+            }
+        }
+        Ok(sum)
+    }
+
+    fn part2(map: &Map) -> Result<i32> {
+        let mut map = map.clone();
+        let mut destinations = HashMap::new();
+        let mut basins = map.heights.map(|_| None);
+        let coors: Vec<_> = map.heights.coors().collect();
+        for coor in coors {
+            let dst = map.follow(coor)?;
+            *destinations.entry(dst).or_insert(0) += 1;
+            if map.heights[coor] != 9 {
+                basins[coor] = Some(dst);
+            }
+        }
+        // the ridges between basins in black
+        frames::save("ppm", |out| {
+            image::write_ppm(out, &basins, |end| end.map_or([0, 0, 0], basin_colour))
+        })?;
+        let mut sizes: Vec<i32> = destinations.values().cloned().collect();
+        sizes.sort_unstable();
+        sizes.reverse();
+        sizes
+            .iter()
+            .take(3)
+            .try_fold(1i32, |product, &size| product.checked_mul(size))
+            .context("product too large")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day09::part1(&Day09::parse(TEST_INPUT)?)?, 15);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day09::part2(&Day09::parse(TEST_INPUT)?)?, 1134);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flows_to_tie() -> Result<()> {
+        let centre = Coor::new(1, 1);
+        let map: Map = "919\n151\n999".parse()?;
+        assert_eq!(map.flows_to(&centre), Some(Coor::new(1, 0)));
+        let map: Map = "999\n151\n919".parse()?;
+        assert_eq!(map.flows_to(&centre), Some(Coor::new(0, 1)));
+        Ok(())
+    }
+}
@@ -1,10 +1,9 @@
-use anyhow::{bail, Context, Result};
-use aoc2021::dispatch;
+use crate::input::{self, At};
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Result};
 use std::collections::VecDeque;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct Day10;
 
 fn pair(c: char) -> Result<char> {
     Ok(match c {
@@ -71,34 +70,57 @@ fn incomplete_points(line: &str) -> Result<u64> {
             _ => bail!("invalid char"),
         }
     }
-    let mut score = 0;
+    let mut score: u64 = 0;
     while let Some(top) = stack.pop_front() {
-        score *= 5;
-        score += incomplete_score(pair(top)?)?;
+        let points = incomplete_score(pair(top)?)?;
+        score = score
+            .checked_mul(5)
+            .and_then(|score| score.checked_add(points))
+            .context("score too large")?;
     }
     Ok(score)
 }
 
-fn part1(input: &str) -> Result<u64> {
-    Ok(input
-        .lines()
-        .map(invalid_points)
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum())
-}
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        input
+            .lines()
+            .map(|line| {
+                for (raw, c) in input::chars(line) {
+                    if !"()[]{}<>".contains(c) {
+                        return Err(anyhow!("invalid char `{}`", c)).at(raw);
+                    }
+                }
+                Ok(line.to_string())
+            })
+            .collect()
+    }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut scores = input
-        .lines()
-        .filter(|l| matches!(invalid_points(l), Ok(0)))
-        .map(incomplete_points)
-        .collect::<Result<Vec<_>>>()?;
-    scores.sort_unstable();
-    scores
-        .get(scores.len() / 2)
-        .context("no score found")
-        .map(|s| *s)
+    fn part1(lines: &Vec<String>) -> Result<u64> {
+        Ok(lines
+            .iter()
+            .map(|l| invalid_points(l))
+            .collect::<Result<Vec<_>>>()?
+            .iter()
+            .sum())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<u64> {
+        let mut scores = lines
+            .iter()
+            .filter(|l| matches!(invalid_points(l), Ok(0)))
+            .map(|l| incomplete_points(l))
+            .collect::<Result<Vec<_>>>()?;
+        scores.sort_unstable();
+        scores
+            .get(scores.len() / 2)
+            .context("no score found")
+            .copied()
+    }
 }
 
 #[cfg(test)]
@@ -118,13 +140,22 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        assert_eq!(part1(TEST_INPUT)?, 26397);
+        assert_eq!(Day10::part1(&Day10::parse(TEST_INPUT)?)?, 26397);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        assert_eq!(part2(TEST_INPUT)?, 288957);
+        assert_eq!(Day10::part2(&Day10::parse(TEST_INPUT)?)?, 288957);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_invalid_char() {
+        let input = "[()]\n[(x)]";
+        let e = input::locate(input, Day10::parse(input).unwrap_err());
+        assert!(e
+            .to_string()
+            .starts_with("line 2, column 3: invalid char `x`"));
+    }
 }
@@ -0,0 +1,148 @@
+use crate::frames;
+use crate::grid::Grid;
+use crate::image;
+use crate::Solution;
+use anyhow::{bail, Context, Error, Result};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub struct Day11;
+
+/// Give up on part 2 after this many steps, in case the octopuses never synchronise
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Clone)]
+pub struct Map {
+    levels: Grid<i32>,
+}
+
+impl Map {
+    fn incr(&mut self) {
+        for (_, val) in self.levels.iter_mut() {
+            *val += 1;
+        }
+    }
+
+    fn flash(&mut self) -> usize {
+        let mut flashing: Vec<_> = self
+            .levels
+            .iter()
+            .filter(|&(_, v)| *v > 9)
+            .map(|(c, _)| c)
+            .collect();
+        let mut flashed: HashSet<_> = flashing.iter().cloned().collect();
+        while !flashing.is_empty() {
+            let mut new_flashing = vec![];
+            for c in &flashing {
+                for neighbour in c.all_neighbours() {
+                    if let Some(n_level) = self.levels.get_mut(neighbour) {
+                        *n_level += 1;
+                        if *n_level > 9 && !flashed.contains(&neighbour) {
+                            flashed.insert(neighbour);
+                            new_flashing.push(neighbour);
+                        }
+                    }
+                }
+            }
+            flashing = new_flashing;
+        }
+        flashed.len()
+    }
+
+    fn reset(&mut self) {
+        for (_, val) in self.levels.iter_mut() {
+            if *val > 9 {
+                *val = 0;
+            }
+        }
+    }
+
+    fn step(&mut self) -> usize {
+        self.incr();
+        let flashes = self.flash();
+        self.reset();
+        flashes
+    }
+
+    /// Octopuses that just flashed in white, the rest darker the lower their level
+    fn save_frame(&self) -> Result<()> {
+        frames::save("pgm", |out| {
+            image::write_pgm(out, &self.levels, |&level| match level {
+                0 => 255,
+                _ => level as u8 * 20,
+            })
+        })
+    }
+
+    fn size(&self) -> usize {
+        self.levels.width() * self.levels.height()
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let levels = Grid::parse_with(s, |c| Ok(c.to_digit(10).context("not a digit")? as i32))?;
+        Ok(Self { levels })
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        let mut flashes = 0;
+        for _ in 0..100 {
+            flashes += map.step();
+            map.save_frame()?;
+        }
+        Ok(flashes)
+    }
+
+    fn part2(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        for step in 1..=MAX_STEPS {
+            let flashes = map.step();
+            map.save_frame()?;
+            if flashes == map.size() {
+                return Ok(step);
+            }
+        }
+        bail!("not all flashing together after {} steps", MAX_STEPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day11::part1(&Day11::parse(TEST_INPUT)?)?, 1656);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day11::part2(&Day11::parse(TEST_INPUT)?)?, 195);
+        Ok(())
+    }
+}
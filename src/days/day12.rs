@@ -0,0 +1,147 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day12;
+
+fn is_big(cave: &str) -> bool {
+    !cave.chars().all(char::is_lowercase)
+}
+
+fn neighbours<'a>(
+    adjacent: &'a HashMap<String, Vec<String>>,
+    cave: &str,
+) -> Result<impl Iterator<Item = &'a str>> {
+    Ok(adjacent
+        .get(cave)
+        .with_context(|| format!("no cave `{}`", cave))?
+        .iter()
+        .map(String::as_str))
+}
+
+impl Solution for Day12 {
+    type Input = HashMap<String, Vec<String>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<HashMap<String, Vec<String>>> {
+        let mut adjacent = HashMap::new();
+        for line in input.lines() {
+            let mut it = line.split('-');
+            let left = it.next().context("no left").at(line)?;
+            let right = it.next().context("no right").at(line)?;
+            if it.next().is_some() {
+                return Err(anyhow!("too many caves")).at(line);
+            }
+            if is_big(left) && is_big(right) {
+                return Err(anyhow!(
+                    "two big caves next to each other make infinitely many paths"
+                ))
+                .at(line);
+            }
+            adjacent
+                .entry(left.to_string())
+                .or_insert_with(Vec::new)
+                .push(right.to_string());
+            adjacent
+                .entry(right.to_string())
+                .or_insert_with(Vec::new)
+                .push(left.to_string());
+        }
+        Ok(adjacent)
+    }
+
+    fn part1(adjacent: &HashMap<String, Vec<String>>) -> Result<i32> {
+        let mut found = 0;
+        let mut queue = vec![(HashSet::from(["start"]), "start")];
+        while let Some((seen, pos)) = queue.pop() {
+            for next in neighbours(adjacent, pos)? {
+                if next == "end" {
+                    found += 1;
+                    continue;
+                }
+                if next.chars().all(char::is_lowercase) && seen.contains(next) {
+                    continue;
+                }
+                let mut new_seen = seen.clone();
+                new_seen.insert(next);
+                queue.push((new_seen, next));
+            }
+        }
+        Ok(found)
+    }
+
+    fn part2(adjacent: &HashMap<String, Vec<String>>) -> Result<i32> {
+        let mut found = 0;
+        let mut queue = vec![(HashSet::new(), false, "start")];
+        while let Some((seen, twice, pos)) = queue.pop() {
+            for next in neighbours(adjacent, pos)? {
+                if next == "start" {
+                    continue;
+                }
+                if next == "end" {
+                    found += 1;
+                    continue;
+                }
+                if next.chars().all(char::is_lowercase) && seen.contains(next) {
+                    if twice {
+                        continue;
+                    }
+                    queue.push((seen.clone(), true, next));
+                    continue;
+                }
+                let mut new_seen = seen.clone();
+                new_seen.insert(next);
+                queue.push((new_seen, twice, next));
+            }
+        }
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const TEST_INPUT2: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day12::part1(&Day12::parse(TEST_INPUT)?)?, 10);
+        assert_eq!(Day12::part1(&Day12::parse(TEST_INPUT2)?)?, 226);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day12::part2(&Day12::parse(TEST_INPUT)?)?, 36);
+        assert_eq!(Day12::part2(&Day12::parse(TEST_INPUT2)?)?, 3509);
+        Ok(())
+    }
+}
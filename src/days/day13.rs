@@ -0,0 +1,241 @@
+use crate::coor::{Coor, Rect};
+use crate::input::At;
+use crate::render;
+use crate::Solution;
+use anyhow::{bail, Context, Error, Result};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub struct Day13;
+
+/// Most characters `part2` will print, so a stray far-off dot can't allocate a huge grid
+const MAX_PRINT: i64 = 100_000;
+
+#[derive(Clone)]
+enum Axis {
+    X,
+    Y,
+}
+
+impl FromStr for Axis {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "x" => Axis::X,
+            "y" => Axis::Y,
+            _ => bail!("invalid axis `{}`", s),
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Fold {
+    axis: Axis,
+    line: i64,
+}
+
+impl Fold {
+    fn apply(&self, val: i64) -> Result<i64> {
+        if val < self.line {
+            Ok(val)
+        } else {
+            self.line
+                .checked_mul(2)
+                .and_then(|double| double.checked_sub(val))
+                .context("fold out of range")
+        }
+    }
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        // fold along x=5";
+        let (_, equation) = s.rsplit_once(' ').context("no equation").at(s)?;
+        let (axis_s, raw_line) = equation
+            .split_once('=')
+            .context("no equal sign")
+            .at(equation)?;
+        let line = raw_line.parse().at(raw_line)?;
+        let axis = axis_s.parse().at(axis_s)?;
+        Ok(Fold { axis, line })
+    }
+}
+
+#[derive(Clone)]
+pub struct Instructions {
+    dots: HashSet<Coor>,
+    folds: Vec<Fold>,
+}
+
+impl Instructions {
+    fn fold(&mut self) -> Result<()> {
+        if self.folds.is_empty() {
+            bail!("no folds left");
+        }
+        let fold = self.folds.remove(0);
+        self.dots = match fold.axis {
+            Axis::X => self
+                .dots
+                .iter()
+                .map(|d| Ok(Coor::new(fold.apply(d.x())?, d.y())))
+                .collect::<Result<_>>()?,
+            Axis::Y => self
+                .dots
+                .iter()
+                .map(|d| Ok(Coor::new(d.x(), fold.apply(d.y())?)))
+                .collect::<Result<_>>()?,
+        };
+        Ok(())
+    }
+
+    fn print(&self) -> Result<String> {
+        let bounds = Rect::from_points(self.dots.iter().copied()).context("no dots")?;
+        // the paper's corner stays put, so start from there even if it's empty
+        // checked one side at a time first, so the area can't overflow
+        if bounds.max.x() >= MAX_PRINT || bounds.max.y() >= MAX_PRINT {
+            bail!("dots up to {:?} are too far apart to print", bounds.max);
+        }
+        let paper = Rect::new(Coor::new(0, 0), bounds.max);
+        if paper.area() > MAX_PRINT {
+            bail!("{}x{} is too large to print", paper.width(), paper.height());
+        }
+        Ok(render::render(paper, |dot| {
+            if self.dots.contains(&dot) {
+                '#'
+            } else {
+                '.'
+            }
+        }))
+    }
+}
+
+impl FromStr for Instructions {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.trim().split("\n\n");
+        let raw_dots = parts.next().context("no coors found")?;
+        let raw_folds = parts.next().context("no folds found")?;
+        if parts.next().is_some() {
+            bail!("too many parts");
+        }
+        let dots: HashSet<Coor> = raw_dots
+            .lines()
+            .map(|l| Coor::from_str(l).at(l))
+            .collect::<Result<_>>()?;
+        let folds: Vec<_> = raw_folds
+            .lines()
+            .map(|l| Fold::from_str(l).at(l))
+            .collect::<Result<_>>()?;
+        Ok(Instructions { dots, folds })
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Instructions;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Instructions> {
+        input.parse()
+    }
+
+    fn part1(instructions: &Instructions) -> Result<usize> {
+        let mut instructions = instructions.clone();
+        instructions.fold()?;
+        Ok(instructions.dots.len())
+    }
+
+    fn part2(instructions: &Instructions) -> Result<String> {
+        let mut instructions = instructions.clone();
+        while !instructions.folds.is_empty() {
+            instructions.fold()?;
+        }
+        render::ocr(&instructions.print()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    const TEST_INPUT: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day13::part1(&Day13::parse(TEST_INPUT)?)?, 17);
+        Ok(())
+    }
+
+    #[test]
+    fn test_print() -> Result<()> {
+        let mut instructions = Day13::parse(TEST_INPUT)?;
+        instructions.fold()?;
+        instructions.fold()?;
+        assert_eq!(
+            instructions.print()?,
+            "#####
+#...#
+#...#
+#...#
+#####
+"
+        );
+        // a square isn't a letter
+        assert!(Day13::part2(&Day13::parse(TEST_INPUT)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        // "HI" drawn upside down on the bottom half, so folding up puts it the right way round
+        let picture = "\
+#..#..###
+#..#...#.
+####...#.
+#..#...#.
+#..#...#.
+#..#..###";
+        let mut input = String::new();
+        for (y, line) in picture.lines().rev().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    writeln!(input, "{},{}", x, y + 7)?;
+                }
+            }
+        }
+        input.push_str("\nfold along y=6");
+        assert_eq!(Day13::part2(&Day13::parse(&input)?)?, "HI");
+        Ok(())
+    }
+
+    #[test]
+    fn test_far() -> Result<()> {
+        let input = "9223372036854775807,0\n\nfold along x=9223372036854775806";
+        assert!(Day13::part1(&Day13::parse(input)?).is_err());
+        let input = "1000000000,0\n0,0\n\nfold along y=5";
+        assert!(Day13::part2(&Day13::parse(input)?).is_err());
+        Ok(())
+    }
+}
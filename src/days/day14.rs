@@ -1,29 +1,29 @@
-use anyhow::{bail, Context, Error, Result};
-use aoc2021::dispatch;
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Error, Result};
 use std::collections::HashMap;
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct Day14;
 
 fn parse_rule(s: &str) -> Result<((char, char), char)> {
-    let (left, right) = s.split_once(" -> ").context("no arrow")?;
+    let (left, right) = s.split_once(" -> ").context("no arrow").at(s)?;
     let mut l_it = left.chars();
-    let c1 = l_it.next().context("left 1 missing")?;
-    let c2 = l_it.next().context("left 2 missing")?;
+    let c1 = l_it.next().context("left 1 missing").at(left)?;
+    let c2 = l_it.next().context("left 2 missing").at(left)?;
     if l_it.next().is_some() {
-        bail!("left: too much input");
+        return Err(anyhow!("left: too much input")).at(left);
     }
     let mut r_it = right.chars();
-    let c3 = r_it.next().context("left 1 missing")?;
+    let c3 = r_it.next().context("left 1 missing").at(right)?;
     if r_it.next().is_some() {
-        bail!("right: too much input");
+        return Err(anyhow!("right: too much input")).at(right);
     }
     Ok(((c1, c2), c3))
 }
 
-struct State {
+#[derive(Clone)]
+pub struct State {
     polymer: HashMap<(char, char), usize>,
     rules: HashMap<(char, char), char>,
     last: char,
@@ -66,7 +66,10 @@ impl FromStr for State {
             *polymer.entry(pair).or_insert(0) += 1;
         }
         let last = raw_polymer.chars().last().context("no last")?;
-        let rules = raw_rules.lines().map(parse_rule).collect::<Result<_>>()?;
+        let rules = raw_rules
+            .lines()
+            .map(|l| parse_rule(l).at(l))
+            .collect::<Result<_>>()?;
         Ok(Self {
             polymer,
             rules,
@@ -75,20 +78,30 @@ impl FromStr for State {
     }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut state: State = input.parse()?;
-    for _ in 0..10 {
-        state.step()?;
+impl Solution for Day14 {
+    type Input = State;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<State> {
+        input.parse()
     }
-    state.quality()
-}
 
-fn part2(input: &str) -> Result<usize> {
-    let mut state: State = input.parse()?;
-    for _ in 0..40 {
-        state.step()?;
+    fn part1(state: &State) -> Result<usize> {
+        let mut state = state.clone();
+        for _ in 0..10 {
+            state.step()?;
+        }
+        state.quality()
+    }
+
+    fn part2(state: &State) -> Result<usize> {
+        let mut state = state.clone();
+        for _ in 0..40 {
+            state.step()?;
+        }
+        state.quality()
     }
-    state.quality()
 }
 
 #[cfg(test)]
@@ -116,13 +129,13 @@ CN -> C";
 
     #[test]
     fn test_part1() -> Result<()> {
-        assert_eq!(part1(TEST_INPUT)?, 1588);
+        assert_eq!(Day14::part1(&Day14::parse(TEST_INPUT)?)?, 1588);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        assert_eq!(part2(TEST_INPUT)?, 2188189693529);
+        assert_eq!(Day14::part2(&Day14::parse(TEST_INPUT)?)?, 2188189693529);
         Ok(())
     }
 }
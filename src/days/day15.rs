@@ -0,0 +1,154 @@
+use crate::coor::Coor;
+use crate::grid::Grid;
+use crate::input::At;
+use crate::search;
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Error, Result};
+use std::str::FromStr;
+
+pub struct Day15;
+
+fn wrap_add(val: usize, addition: usize) -> usize {
+    let mut res = val + addition;
+    while res > 9 {
+        res -= 9
+    }
+    res
+}
+
+#[derive(Clone)]
+pub struct Map {
+    levels: Grid<usize>,
+    size: i64,
+    large: bool,
+}
+
+impl Map {
+    fn get(&self, coor: &Coor) -> Option<usize> {
+        if !self.large {
+            return self.levels.get(*coor).cloned();
+        }
+        if coor.x() >= self.size * 5 || coor.y() >= self.size * 5 {
+            return None;
+        }
+        let addition = coor.x() / self.size + coor.y() / self.size;
+        let scaled_coor = Coor::new(coor.x() % self.size, coor.y() % self.size);
+        self.levels
+            .get(scaled_coor)
+            .map(|v| wrap_add(*v, addition as usize))
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() {
+            bail!("empty map");
+        }
+        let size = s.lines().count() as i64;
+        for line in s.lines() {
+            if line.chars().count() as i64 != size {
+                return Err(anyhow!("expected a square map {} wide", size)).at(line);
+            }
+        }
+        let levels = Grid::parse_with(s, |c| Ok(c.to_digit(10).context("not a digit")? as usize))?;
+
+        Ok(Self {
+            levels,
+            size,
+            large: false,
+        })
+    }
+}
+
+/// The lowest total risk from `start` to `goal`. Every step costs at least 1, so the
+/// manhattan distance left never overestimates
+fn shortest_path(map: &Map, start: Coor, goal: Coor) -> Option<usize> {
+    let successors = |position: &Coor| {
+        position
+            .orthogonal_neighbours()
+            .filter_map(|neighbour| Some((neighbour, map.get(&neighbour)?)))
+            .collect::<Vec<_>>()
+    };
+    let heuristic = |position: &Coor| position.manhattan_to(goal) as usize;
+    search::astar(start, successors, heuristic, |&position| position == goal).map(|(cost, _)| cost)
+}
+
+impl Solution for Day15 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        let end = Coor::new(map.size - 1, map.size - 1);
+        shortest_path(map, Coor::new(0, 0), end).context("no path found")
+    }
+
+    fn part2(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        map.large = true;
+        let end = Coor::new(map.size, map.size) * 5 - Coor::new(1, 1);
+        shortest_path(&map, Coor::new(0, 0), end).context("no path found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day15::part1(&Day15::parse(TEST_INPUT)?)?, 40);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrap_add() {
+        assert_eq!(wrap_add(8, 0), 8);
+        assert_eq!(wrap_add(8, 1), 9);
+        assert_eq!(wrap_add(8, 2), 1);
+        assert_eq!(wrap_add(8, 3), 2);
+        assert_eq!(wrap_add(8, 4), 3);
+        assert_eq!(wrap_add(8, 5), 4);
+        assert_eq!(wrap_add(8, 6), 5);
+        assert_eq!(wrap_add(8, 7), 6);
+        assert_eq!(wrap_add(8, 8), 7);
+    }
+
+    #[test]
+    fn test_large() -> Result<()> {
+        let mut map: Map = TEST_INPUT.parse()?;
+        map.large = true;
+        assert!(map.get(&Coor::new(50, 0)).is_none());
+
+        for x in 0..map.size * 5 {
+            print!("{}", map.get(&Coor::new(x, 49)).expect("missing"));
+        }
+        println!();
+        assert_eq!(map.get(&Coor::new(1, 1)).context("missing")?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day15::part2(&Day15::parse(TEST_INPUT)?)?, 315);
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+use crate::input::{self, At};
+use crate::Solution;
+use anyhow::{bail, Context, Error, Result};
+
+pub struct Day16;
+
+#[derive(Debug)]
+enum Op {
+    Sum,
+    Product,
+    Min,
+    Max,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl TryFrom<usize> for Op {
+    type Error = Error;
+    fn try_from(n: usize) -> Result<Op> {
+        Ok(match n {
+            0 => Op::Sum,
+            1 => Op::Product,
+            2 => Op::Min,
+            3 => Op::Max,
+            5 => Op::GreaterThan,
+            6 => Op::LessThan,
+            7 => Op::EqualTo,
+            _ => bail!("unknown op type {}", n),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Value {
+    Literal(usize),
+    Op(Op, Vec<Packet>),
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    version: usize,
+    value: Value,
+}
+
+impl Packet {
+    fn version_sum(&self) -> usize {
+        self.version
+            + match &self.value {
+                Value::Literal(_) => 0,
+                Value::Op(_, packets) => packets.iter().map(|p| p.version_sum()).sum(),
+            }
+    }
+
+    fn value(&self) -> Result<usize> {
+        let bool_to_int = |b| if b { 1 } else { 0 };
+        let pair = |op: &Op, values: &[usize]| -> Result<(usize, usize)> {
+            match values {
+                &[a, b] => Ok((a, b)),
+                _ => bail!("{:?} needs 2 packets, got {}", op, values.len()),
+            }
+        };
+        Ok(match &self.value {
+            Value::Literal(val) => *val,
+            Value::Op(op, packets) => {
+                let values = packets
+                    .iter()
+                    .map(Packet::value)
+                    .collect::<Result<Vec<_>>>()?;
+                let mut values_it = values.iter().copied();
+                match op {
+                    Op::Sum => values_it
+                        .try_fold(0usize, usize::checked_add)
+                        .context("sum overflowed")?,
+                    Op::Product => values_it
+                        .try_fold(1usize, usize::checked_mul)
+                        .context("product overflowed")?,
+                    Op::Min => values_it.min().context("no packets for min")?,
+                    Op::Max => values_it.max().context("no packets for max")?,
+                    Op::GreaterThan => {
+                        let (a, b) = pair(op, &values)?;
+                        bool_to_int(a > b)
+                    }
+                    Op::LessThan => {
+                        let (a, b) = pair(op, &values)?;
+                        bool_to_int(a < b)
+                    }
+                    Op::EqualTo => {
+                        let (a, b) = pair(op, &values)?;
+                        bool_to_int(a == b)
+                    }
+                }
+            }
+        })
+    }
+}
+
+fn hex_to_bytes(c: char) -> Result<Vec<u8>> {
+    let decimal = u8::from_str_radix(&c.to_string(), 16).context("failed to parse")?;
+    if decimal >= 16 {
+        bail!("too large");
+    }
+    Ok(vec![
+        decimal >> 3 & 1,
+        decimal >> 2 & 1,
+        decimal >> 1 & 1,
+        decimal & 1,
+    ])
+}
+
+fn bytes_to_dec<'a>(digits: impl IntoIterator<Item = &'a u8>) -> Result<usize> {
+    digits
+        .into_iter()
+        .try_fold(0usize, |acc, d| {
+            acc.checked_mul(2)?.checked_add(*d as usize)
+        })
+        .context("number too large")
+}
+
+struct ByteStream {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl ByteStream {
+    fn new(data: Vec<u8>) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn read(&mut self, n: usize) -> Result<&[u8]> {
+        let start = self.pos;
+        if self.len() < n {
+            bail!(
+                "wanted {} more bits at bit {}, only {} left",
+                n,
+                start,
+                self.len()
+            );
+        }
+        self.pos += n;
+        Ok(&self.data[start..self.pos])
+    }
+
+    fn len(&self) -> usize {
+        self.data[self.pos..].len()
+    }
+}
+
+/// Deeper than any real transmission, but shallow enough not to overflow the stack
+const MAX_DEPTH: usize = 1000;
+
+fn process(stream: &mut ByteStream, depth: usize) -> Result<Packet> {
+    if depth > MAX_DEPTH {
+        bail!("packets nested more than {} deep", MAX_DEPTH);
+    }
+    let version = bytes_to_dec(stream.read(3)?)?;
+    let type_id = bytes_to_dec(stream.read(3)?)?;
+    let value = match type_id {
+        4 => {
+            // literal
+            let mut bin_parts = vec![];
+            loop {
+                let chunk = stream.read(5)?;
+                bin_parts.push(chunk[1..].to_vec());
+                if chunk[0] == 0 {
+                    let value = bytes_to_dec(bin_parts.iter().flatten())?;
+                    break Value::Literal(value);
+                }
+            }
+        }
+        op => {
+            // operator
+            let length_type_id = stream.read(1)?[0];
+            let packets = if length_type_id == 0 {
+                let sub_bits = bytes_to_dec(stream.read(15)?)?;
+                let start_len = stream.len();
+                let mut packets = vec![];
+                while start_len - stream.len() < sub_bits {
+                    let packet = process(stream, depth + 1)?;
+                    packets.push(packet);
+                }
+                packets
+            } else if length_type_id == 1 {
+                let sub_packets = bytes_to_dec(stream.read(11)?)?;
+                let mut packets = vec![];
+                while packets.len() < sub_packets {
+                    let packet = process(stream, depth + 1)?;
+                    packets.push(packet);
+                }
+                packets
+            } else {
+                bail!("invalid length_type_id");
+            };
+            Value::Op(Op::try_from(op)?, packets)
+        }
+    };
+    Ok(Packet { version, value })
+}
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Packet> {
+        let digits: Vec<u8> = input::chars(input.trim())
+            .map(|(raw, c)| hex_to_bytes(c).at(raw))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        process(&mut ByteStream::new(digits), 0)
+    }
+
+    fn part1(packet: &Packet) -> Result<usize> {
+        Ok(packet.version_sum())
+    }
+
+    fn part2(packet: &Packet) -> Result<usize> {
+        packet.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "D2FE28";
+
+    #[test]
+    fn test_hex_to_bytes() -> Result<()> {
+        assert_eq!(hex_to_bytes('0')?, vec![0, 0, 0, 0]);
+        assert_eq!(hex_to_bytes('8')?, vec![1, 0, 0, 0]);
+        assert_eq!(hex_to_bytes('9')?, vec![1, 0, 0, 1]);
+        assert_eq!(hex_to_bytes('B')?, vec![1, 0, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day16::part1(&Day16::parse(TEST_INPUT)?)?, 6);
+        assert_eq!(Day16::part1(&Day16::parse("38006F45291200")?)?, 9);
+        assert_eq!(Day16::part1(&Day16::parse("EE00D40C823060")?)?, 14);
+        assert_eq!(Day16::part1(&Day16::parse("8A004A801A8002F478")?)?, 16);
+        assert_eq!(
+            Day16::part1(&Day16::parse("620080001611562C8802118E34")?)?,
+            12
+        );
+        assert_eq!(
+            Day16::part1(&Day16::parse("C0015000016115A2E0802F182340")?)?,
+            23
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day16::part2(&Day16::parse("C200B40A82")?)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() -> Result<()> {
+        // runs out of bits halfway through a literal
+        assert!(Day16::parse("D2FE").is_err());
+        // minimum of no packets
+        assert!(Day16::part2(&Day16::parse("0A0000")?).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use crate::coor::{Coor, Rect};
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Error, Result};
+use std::str::FromStr;
+
+pub struct Day17;
+
+/// Keeps the trajectories well clear of overflow
+const MAX_DISTANCE: i64 = 1 << 30;
+
+#[derive(Debug, PartialEq)]
+pub struct Target {
+    area: Rect,
+}
+
+impl FromStr for Target {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Target> {
+        let s = s.trim();
+        let (_, data) = s.split_once(": ").context("no `: `").at(s)?;
+        let (x_raw, y_raw) = data.split_once(", ").context("no `, `").at(data)?;
+        let x_raw = x_raw.strip_prefix("x=").context("no `x=`").at(x_raw)?;
+        let y_raw = y_raw.strip_prefix("y=").context("no `y=`").at(y_raw)?;
+        let (x_min_raw, x_max_raw) = x_raw.split_once("..").context("x dots").at(x_raw)?;
+        let (y_min_raw, y_max_raw) = y_raw.split_once("..").context("y dots").at(y_raw)?;
+
+        let min = Coor::new(
+            x_min_raw.parse().at(x_min_raw)?,
+            y_min_raw.parse().at(y_min_raw)?,
+        );
+        let max = Coor::new(
+            x_max_raw.parse().at(x_max_raw)?,
+            y_max_raw.parse().at(y_max_raw)?,
+        );
+        if min.x() > max.x() || min.y() > max.y() {
+            return Err(anyhow!("empty target")).at(data);
+        }
+        if min.x() <= 0 || max.y() >= 0 {
+            return Err(anyhow!(
+                "target has to be below and to the right of the launcher"
+            ))
+            .at(data);
+        }
+        if max.x() > MAX_DISTANCE || min.y() < -MAX_DISTANCE {
+            return Err(anyhow!("target is too far away")).at(data);
+        }
+        Ok(Target {
+            area: Rect::new(min, max),
+        })
+    }
+}
+
+fn max_height_if_in_target(v_x: i64, v_y: i64, target: &Target) -> Option<i64> {
+    let mut x = 0;
+    let mut y = 0;
+    let mut v_x = v_x;
+    let mut v_y = v_y;
+    let mut max_height: Option<i64> = None;
+    let mut been_inside = false;
+
+    let Rect { min, max } = target.area;
+    while x <= max.x() && y >= min.y() {
+        x += v_x;
+        y += v_y;
+        been_inside |= target.area.contains(Coor::new(x, y));
+        max_height = match max_height {
+            None => Some(y),
+            Some(max) => Some(max.max(y)),
+        };
+
+        v_x -= v_x.signum();
+        v_y -= 1;
+    }
+
+    if been_inside {
+        max_height
+    } else {
+        None
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Target;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Target> {
+        input.parse()
+    }
+
+    fn part1(target: &Target) -> Result<i64> {
+        let mut max_height = 0;
+        let Rect { min, max } = target.area;
+        for x in 0..max.x() {
+            for y in 0..(-min.y()) {
+                if let Some(height) = max_height_if_in_target(x, y, target) {
+                    max_height = max_height.max(height);
+                }
+            }
+        }
+        Ok(max_height)
+    }
+
+    fn part2(target: &Target) -> Result<usize> {
+        let mut count = 0;
+        let Rect { min, max } = target.area;
+        for x in 0..(max.x() + 1) {
+            for y in min.y()..(-min.y() + 1) {
+                if max_height_if_in_target(x, y, target).is_some() {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(
+            TEST_INPUT.parse::<Target>()?,
+            Target {
+                area: Rect::new(Coor::new(20, -10), Coor::new(30, -5))
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day17::part1(&Day17::parse(TEST_INPUT)?)?, 45);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day17::part2(&Day17::parse(TEST_INPUT)?)?, 112);
+        Ok(())
+    }
+}
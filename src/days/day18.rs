@@ -1,14 +1,13 @@
-use anyhow::{Context, Error, Result};
-use aoc2021::dispatch;
+use crate::input::{self, At};
+use crate::Solution;
+use anyhow::{anyhow, Context, Error, Result};
 use std::fmt::Write;
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct Day18;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-enum Element {
+pub enum Element {
     Number(i32),
     LParen,
     RParen,
@@ -37,17 +36,65 @@ impl FromStr for Element {
     }
 }
 
+/// Input numbers are at most 4 deep, this only keeps the recursion off the end of the stack
+const MAX_DEPTH: usize = 64;
+
+/// Parse a whole snailfish number, which has to be a pair
 fn parse(input: &str) -> Result<Vec<Element>> {
+    let input = input.trim();
+    let end = &input[input.len()..];
+    let mut chars = input::chars(input);
     let mut res = vec![];
-    for c in input.trim().chars() {
-        if c == ',' {
-            continue;
-        }
-        res.push(c.to_string().parse()?)
+    parse_element(&mut chars, end, 0, &mut res)?;
+    if let Some((raw, _)) = chars.next() {
+        return Err(anyhow!("trailing characters")).at(raw);
+    }
+    if res.len() == 1 {
+        return Err(anyhow!("expected a pair, not a number")).at(input);
     }
     Ok(res)
 }
 
+fn expect<'a>(
+    chars: &mut impl Iterator<Item = (&'a str, char)>,
+    end: &str,
+    expected: char,
+) -> Result<()> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((raw, _)) => Err(anyhow!("expected `{}`", expected)).at(raw),
+        None => Err(anyhow!("expected `{}`", expected)).at(end),
+    }
+}
+
+fn parse_element<'a>(
+    chars: &mut impl Iterator<Item = (&'a str, char)>,
+    end: &str,
+    depth: usize,
+    res: &mut Vec<Element>,
+) -> Result<()> {
+    use Element::*;
+    match chars.next() {
+        Some((raw, '[')) => {
+            if depth == MAX_DEPTH {
+                return Err(anyhow!("nested too deeply")).at(raw);
+            }
+            res.push(LParen);
+            parse_element(chars, end, depth + 1, res)?;
+            expect(chars, end, ',')?;
+            parse_element(chars, end, depth + 1, res)?;
+            expect(chars, end, ']')?;
+            res.push(RParen);
+        }
+        Some((raw, c)) => {
+            let n = c.to_digit(10).context("expected a digit or `[`").at(raw)?;
+            res.push(Number(n as i32));
+        }
+        None => return Err(anyhow!("unexpected end")).at(end),
+    }
+    Ok(())
+}
+
 fn _print(pair: &[Element]) -> String {
     use Element::*;
     let mut output = String::new();
@@ -307,35 +354,47 @@ fn sum(pair: &[Element]) -> i32 {
     total
 }
 
-fn add_list(list: &[Vec<Element>]) -> Vec<Element> {
+fn add_list(list: &[Vec<Element>]) -> Result<Vec<Element>> {
     let mut it = list.iter();
-    let mut acc: Vec<Element> = it.next().unwrap().to_vec();
+    let mut acc: Vec<Element> = it.next().context("no numbers to add")?.to_vec();
     for el in it {
         acc = add(&acc, el);
     }
-    acc
+    Ok(acc)
 }
 
 fn parse_list(input: &str) -> Result<Vec<Vec<Element>>> {
-    input.trim().lines().map(parse).collect::<Result<Vec<_>>>()
+    input
+        .trim()
+        .lines()
+        .map(|l| parse(l).at(l))
+        .collect::<Result<Vec<_>>>()
 }
 
-fn part1(input: &str) -> Result<i32> {
-    let list: Vec<Vec<Element>> = parse_list(input)?;
-    let res = add_list(&list);
-    Ok(sum(&res))
-}
+impl Solution for Day18 {
+    type Input = Vec<Vec<Element>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Element>>> {
+        parse_list(input)
+    }
+
+    fn part1(list: &Vec<Vec<Element>>) -> Result<i32> {
+        let res = add_list(list)?;
+        Ok(sum(&res))
+    }
 
-fn part2(input: &str) -> Result<i32> {
-    let list: Vec<Vec<Element>> = parse_list(input)?;
-    let mut max = 0;
-    for i in 0..list.len() {
-        for j in i + 1..list.len() {
-            max = max.max(sum(&add(&list[i], &list[j])));
-            max = max.max(sum(&add(&list[j], &list[i])));
+    fn part2(list: &Vec<Vec<Element>>) -> Result<i32> {
+        let mut max = 0;
+        for i in 0..list.len() {
+            for j in i + 1..list.len() {
+                max = max.max(sum(&add(&list[i], &list[j])));
+                max = max.max(sum(&add(&list[j], &list[i])));
+            }
         }
+        Ok(max)
     }
-    Ok(max)
 }
 
 #[cfg(test)]
@@ -346,7 +405,7 @@ mod tests {
     #[test]
     fn test_part1() -> Result<()> {
         assert_eq!(
-            part1(
+            Day18::part1(&Day18::parse(
                 "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
 [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
@@ -357,7 +416,7 @@ mod tests {
 [[9,3],[[9,9],[6,[4,9]]]]
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
-            )?,
+            )?)?,
             4140
         );
         Ok(())
@@ -366,7 +425,7 @@ mod tests {
     #[test]
     fn test_part2() -> Result<()> {
         assert_eq!(
-            part2(
+            Day18::part2(&Day18::parse(
                 "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
 [6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
@@ -377,7 +436,7 @@ mod tests {
 [[9,3],[[9,9],[6,[4,9]]]]
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
-            )?,
+            )?)?,
             3993
         );
         Ok(())
@@ -472,6 +531,16 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_malformed() -> Result<()> {
+        assert!(Day18::part1(&vec![]).is_err());
+        assert!(parse("[1,2").is_err());
+        assert!(parse("[1 2]").is_err());
+        assert!(parse("[[1,2],3]]").is_err());
+        assert!(parse("7").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_sum() -> Result<()> {
         assert_eq!(sum(&parse("[9,1]")?), 29);
@@ -504,7 +573,7 @@ mod tests {
 [2,2]
 [3,3]
 [4,4]"
-            )?)),
+            )?)?),
             "[[[[1,1],[2,2]],[3,3]],[4,4]]"
         );
 
@@ -520,7 +589,7 @@ mod tests {
 [1,[[[9,3],9],[[9,0],[0,7]]]]
 [[[5,[7,4]],7],1]
 [[[[4,2],2],6],[8,7]]"
-            )?)),
+            )?)?),
             "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
         );
 
@@ -536,7 +605,7 @@ mod tests {
 [[9,3],[[9,9],[6,[4,9]]]]
 [[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
 [[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"
-            )?)),
+            )?)?),
             "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
         );
         Ok(())
@@ -0,0 +1,199 @@
+use crate::coor3::{Coor3, Rotation, Transform};
+use crate::input::At;
+use crate::Solution;
+use anyhow::{bail, Context, Result};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day19;
+
+const TOO_FAR: &str = "beacons are too far apart";
+
+fn offsets(coors: &[Coor3], rotation: &Rotation) -> Result<Vec<(usize, usize, Coor3)>> {
+    let mut relative = vec![];
+    for i in 0..coors.len() {
+        for j in i + 1..coors.len() {
+            let offset = coors[j]
+                .checked_sub(coors[i])
+                .and_then(|offset| rotation.checked_apply(offset))
+                .context(TOO_FAR)?;
+            relative.push((i, j, offset));
+        }
+    }
+    Ok(relative)
+}
+
+/// How to move `scanner2`'s beacons to where `scanner1` sees them, if they share enough
+fn offset(scanner1: &[Coor3], scanner2: &[Coor3]) -> Result<Option<Transform>> {
+    let relative1 = offsets(scanner1, &Rotation::IDENTITY)?;
+    for rotation in Rotation::all() {
+        let relative2 = offsets(scanner2, rotation)?;
+        let relative_set1 = relative1.iter().map(|(_, _, c)| c).collect::<HashSet<_>>();
+        let relative_set2 = relative2.iter().map(|(_, _, c)| c).collect::<HashSet<_>>();
+        let intersection = relative_set1.intersection(&relative_set2);
+        if intersection.count() >= 6 {
+            let mut offsets = HashMap::new();
+            for &(i1, _j1, c1) in &relative1 {
+                for &(i2, _j2, c2) in &relative2 {
+                    if c1 == c2 {
+                        let offset1 = rotation
+                            .checked_apply(scanner2[i2])
+                            .and_then(|c2| scanner1[i1].checked_sub(c2))
+                            .context(TOO_FAR)?;
+                        *offsets.entry(offset1).or_insert(0) += 1;
+                    }
+                }
+            }
+            if let Some((&count, &offset)) = offsets.iter().map(|(k, v)| (v, k)).max() {
+                if count >= 12 {
+                    return Ok(Some(Transform::new(*rotation, offset)));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn parse_scanners(input: &str) -> Result<Vec<Vec<Coor3>>> {
+    let mut res = vec![];
+    for scanner in input.trim().split("\n\n") {
+        let (_header, raw_coors) = scanner.split_once('\n').context("no newline").at(scanner)?;
+        let coors: Vec<Coor3> = raw_coors
+            .lines()
+            .map(|l| l.parse().at(l))
+            .collect::<Result<_>>()?;
+        res.push(coors);
+    }
+    Ok(res)
+}
+
+/// All beacons and scanner positions, relative to scanner 0
+pub struct Aligned {
+    beacons: HashSet<Coor3>,
+    scanner_pos: Vec<Coor3>,
+}
+
+fn align(scanners: Vec<Vec<Coor3>>) -> Result<Aligned> {
+    // from each scanner's view to scanner 0's, once known
+    let mut to_first: Vec<Option<Transform>> = vec![None; scanners.len()];
+    to_first[0] = Some(Transform::IDENTITY);
+    let mut done = vec![0];
+    let mut found = true;
+    while found {
+        found = false;
+        for &i in &done.clone() {
+            for j in 0..scanners.len() {
+                if to_first[j].is_some() {
+                    continue;
+                }
+                if let Some(j_to_i) = offset(&scanners[i], &scanners[j])? {
+                    to_first[j] = to_first[i]
+                        .map(|i_to_first| i_to_first.checked_compose(j_to_i).context(TOO_FAR))
+                        .transpose()?;
+                    done.push(j);
+                    found = true;
+                }
+            }
+        }
+    }
+    let to_first: Vec<Transform> = match to_first.iter().copied().collect::<Option<_>>() {
+        Some(to_first) => to_first,
+        None => {
+            let missing: Vec<_> = (0..scanners.len())
+                .filter(|&i| to_first[i].is_none())
+                .collect();
+            bail!("scanners {:?} don't overlap with scanner 0", missing);
+        }
+    };
+    Ok(Aligned {
+        beacons: scanners
+            .iter()
+            .zip(&to_first)
+            .flat_map(|(beacons, &transform)| {
+                beacons.iter().map(move |&b| transform.checked_apply(b))
+            })
+            .collect::<Option<_>>()
+            .context(TOO_FAR)?,
+        scanner_pos: to_first.iter().map(|t| t.translation).collect(),
+    })
+}
+
+impl Solution for Day19 {
+    type Input = Aligned;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Aligned> {
+        align(parse_scanners(input)?)
+    }
+
+    fn part1(aligned: &Aligned) -> Result<usize> {
+        Ok(aligned.beacons.len())
+    }
+
+    fn part2(aligned: &Aligned) -> Result<i64> {
+        let mut max = 0;
+        for &d1 in &aligned.scanner_pos {
+            for &d2 in &aligned.scanner_pos {
+                max = max.max(d1.checked_manhattan_to(d2).context(TOO_FAR)?);
+            }
+        }
+        Ok(max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../input/day19.sample");
+
+    #[test]
+    fn test_offset() -> Result<()> {
+        let scanners = parse_scanners(TEST_INPUT)?;
+        assert_eq!(
+            offset(&scanners[0], &scanners[1])?.unwrap().translation,
+            "68,-1246,-43".parse()?
+        );
+        assert_ne!(
+            dbg!(offset(&scanners[0], &scanners[1])?.unwrap().rotation),
+            Rotation::IDENTITY
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_offset2() -> Result<()> {
+        let scanners = parse_scanners(TEST_INPUT)?;
+        offset(&scanners[1], &scanners[4])?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_chained() -> Result<()> {
+        // scanner 4 is only found through scanner 1
+        let scanners = parse_scanners(TEST_INPUT)?;
+        let aligned = align(scanners)?;
+        assert_eq!(aligned.scanner_pos[4], "-20,-1133,1061".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day19::part1(&Day19::parse(TEST_INPUT)?)?, 79);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day19::part2(&Day19::parse(TEST_INPUT)?)?, 3621);
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_far() {
+        let input =
+            "--- scanner 0 ---\n9223372036854775807,0,0\n-1,0,0\n\n--- scanner 1 ---\n0,0,0";
+        assert!(Day19::parse(input).is_err());
+    }
+}
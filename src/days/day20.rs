@@ -0,0 +1,183 @@
+use crate::coor::{Coor, Rect};
+use crate::frames;
+use crate::grid::Grid;
+use crate::image;
+use crate::input::{self, At};
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Error, Result};
+use std::str::FromStr;
+
+pub struct Day20;
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    bitmap: Vec<u8>,
+    pixels: Grid<u8>,
+    /// Where the top left of `pixels` is in the infinite image
+    origin: Coor,
+    /// The value of every pixel outside `pixels`
+    background: u8,
+}
+
+fn parse_pixel(c: char) -> Result<u8> {
+    Ok(match c {
+        '#' => 1,
+        '.' => 0,
+        _ => bail!("invalid pixel value `{}`", c),
+    })
+}
+
+impl Map {
+    /// Grow the image by 2 on each side, which is as far as the window can see
+    fn process(&mut self) {
+        let bounds = self.bounds().expand(2);
+        let mut next = Grid::new(bounds.width() as usize, bounds.height() as usize, 0);
+        for coor in bounds.points() {
+            let mut val: u16 = 0;
+            for pixel in coor.window(1) {
+                val *= 2;
+                val += self.pixel(pixel) as u16;
+            }
+            next[coor - bounds.min] = self.bitmap[val as usize];
+        }
+        if self.bitmap[0] == 1 {
+            self.background = 1 - self.background;
+        }
+        self.pixels = next;
+        self.origin = bounds.min;
+    }
+
+    /// Where `pixels` is in the image
+    fn bounds(&self) -> Rect {
+        let size = Coor::new(self.pixels.width() as i64, self.pixels.height() as i64);
+        Rect::new(self.origin, self.origin + size - Coor::new(1, 1))
+    }
+
+    fn pixel(&self, coor: Coor) -> u8 {
+        *self
+            .pixels
+            .get(coor - self.origin)
+            .unwrap_or(&self.background)
+    }
+
+    /// Lit pixels in black. The frames grow by 4 pixels each step
+    fn save_frame(&self) -> Result<()> {
+        frames::save("pbm", |out| {
+            image::write_pbm(out, &self.pixels, |&v| v == 1)
+        })
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (bitmap_raw, pixels_raw) = s.split_once("\n\n").context("no separator").at(s)?;
+        let bitmap = input::chars(bitmap_raw)
+            .map(|(raw, c)| parse_pixel(c).at(raw))
+            .collect::<Result<Vec<_>>>()?;
+        if pixels_raw.trim().is_empty() {
+            return Err(anyhow!("empty image")).at(pixels_raw);
+        }
+        let pixels = Grid::parse_with(pixels_raw, parse_pixel)?;
+        Ok(Map {
+            bitmap,
+            pixels,
+            origin: Coor::new(0, 0),
+            background: 0,
+        })
+    }
+}
+
+impl Solution for Day20 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        let map: Map = input.parse()?;
+        // shorter ones are fine for comparing images in the tests, but can't be processed
+        if map.bitmap.len() != 512 {
+            let bitmap_raw = input.trim().lines().next().unwrap_or(input);
+            return Err(anyhow!("expected 512 pixels, got {}", map.bitmap.len())).at(bitmap_raw);
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        for _ in 0..2 {
+            map.process();
+            map.save_frame()?;
+        }
+
+        Ok(map.pixels.values().filter(|&v| *v == 1).count())
+    }
+
+    fn part2(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        for _ in 0..50 {
+            map.process();
+            map.save_frame()?;
+        }
+
+        Ok(map.pixels.values().filter(|&v| *v == 1).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../input/day20.sample");
+
+    const IT1: &str = "#
+
+.....##.##.....
+....#..#.#.....
+....##.#..#....
+....####..#....
+.....#..##.....
+......##..#....
+.......#.#.....";
+
+    const IT2: &str = "#
+
+...............
+...............
+...............
+..........#....
+....#..#.#.....
+...#.#...###...
+...#...##.#....
+...#.....#.#...
+....#.#####....
+.....#.#####...
+......##.##....
+.......###.....
+...............
+...............
+...............";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let lit = |map: &Map| map.pixels.values().filter(|&v| *v == 1).count();
+        let mut map: Map = TEST_INPUT.parse()?;
+        let it1: Map = IT1.parse()?;
+        let it2: Map = IT2.parse()?;
+
+        map.process();
+        assert_eq!(lit(&map), lit(&it1));
+        map.process();
+        assert_eq!(lit(&map), lit(&it2));
+
+        assert_eq!(Day20::part1(&Day20::parse(TEST_INPUT)?)?, 35);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day20::part2(&Day20::parse(TEST_INPUT)?)?, 3351);
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+
+pub struct Day21;
+
+fn take3(it: &mut dyn Iterator<Item = u64>) -> u64 {
+    it.next().unwrap() + it.next().unwrap() + it.next().unwrap()
+}
+
+/*
+
+111  3
+112  4
+113  5
+121  4
+122  5
+123  6
+131  5
+132  6
+133  7
+
+3 4 4 5 5 5 6 6 7
+4 5 5 6 6 6 7 7 8
+5 6 6 7 7 7 8 8 9
+
+3
+4 4 4
+5 5 5 5 5 5
+6 6 6 6 6 6 6
+7 7 7 7 7 7
+8 8 8
+9
+
+     */
+
+const DICE: [(u64, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+impl Solution for Day21 {
+    type Input = [u64; 2];
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<[u64; 2]> {
+        let input = input.trim();
+        let (p1, p2) = input.split_once('\n').context("no newline").at(input)?;
+        let start = |line: &str| -> Result<u64> {
+            let (_, raw) = line.rsplit_once(' ').context("no space").at(line)?;
+            let position = raw.parse().context("not a number").at(raw)?;
+            if !(1..=10).contains(&position) {
+                return Err(anyhow!("the board goes from 1 to 10")).at(raw);
+            }
+            Ok(position)
+        };
+        Ok([start(p1)?, start(p2)?])
+    }
+
+    fn part1(starting_positions: &[u64; 2]) -> Result<u64> {
+        let mut it = 1..;
+        let mut scores = [0, 0];
+        let mut positions = *starting_positions;
+        let mut throws = 0;
+        Ok(loop {
+            let points = take3(&mut it);
+            let player = throws % 2;
+            positions[player] = (positions[player] + points) % 10;
+            scores[player] += if positions[player] == 0 {
+                10
+            } else {
+                positions[player]
+            };
+            throws += 3;
+            if scores[player] >= 1000 {
+                let other = (player + 1) % 2;
+                break throws as u64 * scores[other];
+            }
+        })
+    }
+
+    fn part2(starting_positions: &[u64; 2]) -> Result<u64> {
+        let mut positions = HashMap::new();
+        positions.insert((*starting_positions, [0, 0]), 1);
+        let mut player = 0;
+        let mut wins = [0, 0];
+
+        loop {
+            let mut new_positions = HashMap::new();
+            for (throw, throw_count) in DICE {
+                for (state, count) in &positions {
+                    let (position, scores) = state;
+                    let mut new_position = *position;
+                    let mut new_scores = *scores;
+                    new_position[player] = (new_position[player] + throw) % 10;
+                    new_scores[player] += if new_position[player] == 0 {
+                        10
+                    } else {
+                        new_position[player]
+                    };
+                    if new_scores[player] >= 21 {
+                        wins[player] += count * throw_count;
+                    } else {
+                        *new_positions.entry((new_position, new_scores)).or_insert(0) +=
+                            count * throw_count;
+                    }
+                }
+            }
+            if new_positions.is_empty() {
+                break;
+            }
+            positions = new_positions;
+            player = (player + 1) % 2;
+        }
+
+        Ok(wins[0].max(wins[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day21::part1(&Day21::parse(TEST_INPUT)?)?, 739785);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        assert_eq!(Day21::part2(&Day21::parse(TEST_INPUT)?)?, 444356092776315);
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use crate::coor3::{Coor3, Cuboid, CuboidSet};
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, Context, Result};
+
+pub struct Day22;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Step {
+    on: bool,
+    cuboid: Cuboid,
+}
+
+fn split(s: &str, axis: &str) -> Result<(i64, i64)> {
+    let range = s
+        .strip_prefix(axis)
+        .and_then(|s| s.strip_prefix('='))
+        .with_context(|| format!("no `{}=`", axis))
+        .at(s)?;
+    let (a, b) = range.split_once("..").context("dots").at(range)?;
+    let (a, b): (i64, i64) = (a.parse().context("a").at(a)?, b.parse().context("b").at(b)?);
+    if a > b {
+        return Err(anyhow!("empty range")).at(range);
+    }
+    // cutting a cuboid out of another steps one past each of its faces
+    if a == i64::MIN || b == i64::MAX {
+        return Err(anyhow!("coordinate out of range")).at(range);
+    }
+    Ok((a, b))
+}
+
+fn parse_line(s: &str) -> Result<Step> {
+    let s = s.trim();
+    let (on_raw, rest) = s.split_once(' ').context("space").at(s)?;
+    let on = match on_raw {
+        "on" => true,
+        "off" => false,
+        _ => return Err(anyhow!("expected `on` or `off`")).at(on_raw),
+    };
+    let (x_raw, rest) = rest.split_once(',').context("x").at(rest)?;
+    let (y_raw, z_raw) = rest.split_once(',').context("y").at(rest)?;
+    let x = split(x_raw, "x")?;
+    let y = split(y_raw, "y")?;
+    let z = split(z_raw, "z")?;
+    Ok(Step {
+        on,
+        cuboid: Cuboid::new(Coor3::new(x.0, y.0, z.0), Coor3::new(x.1, y.1, z.1)),
+    })
+}
+
+fn apply(steps: &[Step]) -> Result<i64> {
+    let mut reactor = CuboidSet::new();
+    for step in steps {
+        if step.on {
+            reactor.add(step.cuboid);
+        } else {
+            reactor.remove(&step.cuboid);
+        }
+    }
+    reactor.checked_volume().context("volume too large")
+}
+
+impl Solution for Day22 {
+    type Input = Vec<Step>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> Result<Vec<Step>> {
+        s.trim()
+            .lines()
+            .map(|l| parse_line(l).at(l))
+            .collect::<Result<Vec<_>>>()
+    }
+
+    fn part1(steps: &Vec<Step>) -> Result<i64> {
+        let region = Cuboid::new(Coor3::new(-50, -50, -50), Coor3::new(50, 50, 50));
+        let steps: Vec<_> = steps
+            .iter()
+            .filter_map(|s| {
+                Some(Step {
+                    on: s.on,
+                    cuboid: s.cuboid.intersection(&region)?,
+                })
+            })
+            .collect();
+        apply(&steps)
+    }
+
+    fn part2(steps: &Vec<Step>) -> Result<i64> {
+        apply(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10";
+
+    const TEST_INPUT2: &str = "on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682";
+
+    const TEST_INPUT3: &str = include_str!("../../input/day22.sample");
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day22::part1(&Day22::parse(TEST_INPUT)?)?, 39);
+        assert_eq!(Day22::part1(&Day22::parse(TEST_INPUT2)?)?, 590784);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects1() -> Result<()> {
+        let steps = Day22::parse(
+            "on x=1..3,y=4..5,z=0..0
+on x=3..5,y=5..6,z=0..0",
+        )?;
+        assert!(steps[0].cuboid.intersection(&steps[1].cuboid).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects2() -> Result<()> {
+        let steps = Day22::parse(
+            "on x=-20..34,y=-40..6,z=-44..1
+on x=-57795..-6158,y=29564..72030,z=20435..90618",
+        )?;
+        assert!(steps[0].cuboid.intersection(&steps[1].cuboid).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects3() -> Result<()> {
+        let steps = Day22::parse(
+            "on x=1..3,y=0..0,z=0..0
+on x=3..5,y=0..0,z=0..0",
+        )?;
+        assert!(steps[0].cuboid.intersection(&steps[1].cuboid).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects4() -> Result<()> {
+        let steps = Day22::parse(
+            "on x=2..3,y=0..0,z=0..0
+on x=3..3,y=0..0,z=0..0",
+        )?;
+        assert!(steps[0].cuboid.intersection(&steps[1].cuboid).is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2a() -> Result<()> {
+        assert_eq!(Day22::part2(&Day22::parse(TEST_INPUT)?)?, 39);
+        Ok(())
+    }
+
+    /*
+
+     xxx  o*o  xxx
+     xxx  o o  xxx
+     xxx  o*o  xxx
+
+    */
+
+    #[test]
+    fn test_part2c() -> Result<()> {
+        assert_eq!(
+            Day22::part2(&Day22::parse(
+                "on x=1..3,y=1..3,z=1..3
+off x=2..2,y=2..2,z=2..2"
+            )?)?,
+            26
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2b() -> Result<()> {
+        assert_eq!(Day22::part2(&Day22::parse(TEST_INPUT3)?)?, 2758514936282235);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2d() -> Result<()> {
+        let input = "on x=-20..26,y=-36..17,z=-47..7
+        on x=-20..33,y=-21..23,z=-26..28
+        on x=-22..28,y=-29..23,z=-38..16
+        on x=-46..7,y=-6..46,z=-50..-1
+        on x=-49..1,y=-3..46,z=-24..28
+        on x=2..47,y=-22..22,z=-23..27
+        on x=-27..23,y=-28..26,z=-21..29
+        on x=-39..5,y=-6..47,z=-3..44
+        on x=-30..21,y=-8..43,z=-13..34
+        on x=-22..26,y=-27..20,z=-29..19
+        off x=-48..-32,y=26..41,z=-47..-37
+        on x=-12..35,y=6..50,z=-50..-2
+        off x=-48..-32,y=-32..-16,z=-15..-5
+        on x=-18..26,y=-33..15,z=-7..46
+        off x=-40..-22,y=-38..-28,z=23..41
+        on x=-16..35,y=-41..10,z=-47..6
+        off x=-32..-23,y=11..30,z=-14..3
+        on x=-49..-5,y=-3..45,z=-29..18
+        off x=18..30,y=-20..-8,z=-3..13
+        on x=-41..9,y=-7..43,z=-33..15";
+        let input =
+            itertools::Itertools::intersperse(input.lines().take(2), "\n").collect::<String>();
+        let steps = Day22::parse(&input)?;
+        assert_eq!(Day22::part1(&steps)?, Day22::part2(&steps)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_large() -> Result<()> {
+        let steps = Day22::parse("on x=0..1000000000,y=0..1000000000,z=0..1000000000")?;
+        assert!(Day22::part2(&steps).is_err());
+        assert!(Day22::parse("on x=0..9223372036854775807,y=0..1,z=0..1").is_err());
+        Ok(())
+    }
+}
@@ -1,16 +1,13 @@
-use anyhow::{Context, Error, Result};
-use aoc2021::dispatch;
-use itertools::Itertools;
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use crate::input::{self, At};
+use crate::search;
+use crate::Solution;
+use anyhow::{anyhow, Context, Error, Result};
 use std::str::FromStr;
 
 const ROOMS: usize = 4;
 const HALLS: usize = 11;
 
-fn main() -> Result<()> {
-    dispatch(part1, part2)
-}
+pub struct Day23;
 
 type Amphipod = usize;
 type Row = [Option<usize>; 4];
@@ -88,13 +85,6 @@ fn hall_to_room(from: usize, to: usize) -> (std::ops::RangeInclusive<usize>, usi
 }
 
 impl<const LEVELS: usize> Position<LEVELS> {
-    fn num_correct(&self) -> usize {
-        self.rooms
-            .map(|r| r.iter().zip(0..3).filter(|(a, b)| **a == Some(*b)).count())
-            .iter()
-            .sum()
-    }
-
     fn room_to_hall_len(&self, from: usize, to: usize) -> Option<usize> {
         let (path, path_len) = room_to_hall(from, to);
         if path.into_iter().all(|h| self.hallway[h].is_none()) {
@@ -128,14 +118,13 @@ impl<const LEVELS: usize> Position<LEVELS> {
         {
             return None;
         }
-        return self
-            .rooms
+        self.rooms
             .iter()
             .map(|row| row[room])
             .enumerate()
             .filter(|(_, occupant)| occupant.is_none())
             .map(|(level, _)| level)
-            .last();
+            .next_back()
     }
 
     fn top_occupant(&self, room: usize) -> Option<(usize, Amphipod)> {
@@ -210,201 +199,127 @@ impl<const LEVELS: usize> Position<LEVELS> {
     }
 }
 
-fn parse_line(s: &str) -> [Option<usize>; 4] {
-    let amphipod_index = |c| match c {
-        'A'..='D' => Some(c as usize - 'A' as usize),
-        _ => None,
-    };
-    [
-        s.chars().nth(3).map(amphipod_index).unwrap(),
-        s.chars().nth(5).map(amphipod_index).unwrap(),
-        s.chars().nth(7).map(amphipod_index).unwrap(),
-        s.chars().nth(9).map(amphipod_index).unwrap(),
-    ]
+/// The amphipod in `column` of `line`, if there is one
+fn cell(line: &str, column: usize) -> Result<Option<Amphipod>> {
+    let (raw, c) = input::chars(line)
+        .nth(column)
+        .context("line too short")
+        .at(line)?;
+    match c {
+        'A'..='D' => Ok(Some(c as usize - 'A' as usize)),
+        '.' => Ok(None),
+        _ => Err(anyhow!("expected an amphipod or `.`")).at(raw),
+    }
 }
 
-fn parse_input(s: &str) -> Result<(Row, Row)> {
-    let lines = s.trim().lines().skip(2).take(4);
-    let (top, bottom) = lines.tuple_windows().next().unwrap();
-
-    Ok((parse_line(top), parse_line(bottom)))
+fn parse_line(s: &str) -> Result<Row> {
+    Ok([cell(s, 3)?, cell(s, 5)?, cell(s, 7)?, cell(s, 9)?])
 }
 
-impl FromStr for Position<2> {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self> {
-        let hallway = [None; HALLS];
-        let (rooms_0, rooms_1) = parse_input(s)?;
+fn parse_input(s: &str) -> Result<(Row, Row)> {
+    let s = s.trim();
+    let mut lines = s.lines().skip(2);
+    let top = lines.next().context("no rooms").at(s)?;
+    let bottom = lines.next().context("only one row of rooms").at(s)?;
+    let rows = (parse_line(top)?, parse_line(bottom)?);
+
+    for amphipod in 0..ROOMS {
+        let count = rows
+            .0
+            .iter()
+            .chain(&rows.1)
+            .filter(|&&a| a == Some(amphipod))
+            .count();
+        if count != 2 {
+            return Err(anyhow!("expected two of each amphipod in the rooms")).at(top);
+        }
+    }
+    Ok(rows)
+}
 
-        Ok(Position {
-            hallway,
+impl From<(Row, Row)> for Position<2> {
+    fn from((rooms_0, rooms_1): (Row, Row)) -> Self {
+        Position {
+            hallway: [None; HALLS],
             rooms: [rooms_0, rooms_1],
-        })
+        }
     }
 }
 
-impl FromStr for Position<4> {
-    type Err = Error;
-    fn from_str(s: &str) -> Result<Self> {
-        let hallway = [None; HALLS];
-        let (rooms_0, rooms_3) = parse_input(s)?;
+impl From<(Row, Row)> for Position<4> {
+    fn from((rooms_0, rooms_3): (Row, Row)) -> Self {
         // #D#C#B#A#
         // #D#B#A#C#
         let rooms_1 = [Some(3), Some(2), Some(1), Some(0)];
         let rooms_2 = [Some(3), Some(1), Some(0), Some(2)];
 
-        Ok(Position {
-            hallway,
+        Position {
+            hallway: [None; HALLS],
             rooms: [rooms_0, rooms_1, rooms_2, rooms_3],
-        })
+        }
     }
 }
 
-#[allow(dead_code)]
-fn parse4(s: &str) -> Result<Position<4>> {
-    let top = s.trim().lines().nth(1).unwrap();
-
-    let amphipod_index = |c| match c {
-        'A'..='D' => Some(c as usize - 'A' as usize),
-        _ => None,
-    };
-
-    let hallway = [
-        top.chars().nth(1).map(amphipod_index).unwrap(),
-        top.chars().nth(2).map(amphipod_index).unwrap(),
-        top.chars().nth(3).map(amphipod_index).unwrap(),
-        top.chars().nth(4).map(amphipod_index).unwrap(),
-        top.chars().nth(5).map(amphipod_index).unwrap(),
-        top.chars().nth(6).map(amphipod_index).unwrap(),
-        top.chars().nth(7).map(amphipod_index).unwrap(),
-        top.chars().nth(8).map(amphipod_index).unwrap(),
-        top.chars().nth(9).map(amphipod_index).unwrap(),
-        top.chars().nth(10).map(amphipod_index).unwrap(),
-        top.chars().nth(11).map(amphipod_index).unwrap(),
-    ];
-
-    let lines = s.trim().lines().skip(2).take(4);
-    let (r0, r1, r2, r3) = lines.tuple_windows().next().unwrap();
-
-    Ok(Position {
-        hallway,
-        rooms: [
-            parse_line(r0),
-            parse_line(r1),
-            parse_line(r2),
-            parse_line(r3),
-        ],
-    })
+impl<const LEVELS: usize> FromStr for Position<LEVELS>
+where
+    Position<LEVELS>: From<(Row, Row)>,
+{
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(parse_input(s)?.into())
+    }
 }
 
-// https://doc.rust-lang.org/std/collections/binary_heap/index.html
+#[allow(dead_code)]
+fn parse4(s: &str) -> Result<Position<4>> {
+    let s = s.trim();
+    let mut lines = s.lines().skip(1);
+    let top = lines.next().context("no hallway").at(s)?;
 
-#[derive(Clone, Eq, PartialEq)]
-struct State<const LEVELS: usize> {
-    position: Position<LEVELS>,
-    cost: usize,
-}
-// The priority queue depends on `Ord`.
-// Explicitly implement the trait so the queue becomes a min-heap
-// instead of a max-heap.
-impl<const LEVELS: usize> Ord for State<LEVELS> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Notice that the we flip the ordering on costs.
-        // In case of a tie we compare positions - this step is necessary
-        // to make implementations of `PartialEq` and `Ord` consistent.
-        other.cost.cmp(&self.cost).then_with(|| {
-            (self.position.num_correct(), &self.position)
-                .cmp(&(other.position.num_correct(), &other.position))
-        })
+    let mut hallway = [None; HALLS];
+    for (h, slot) in hallway.iter_mut().enumerate() {
+        *slot = cell(top, h + 1)?;
     }
-}
 
-// `PartialOrd` needs to be implemented as well.
-impl<const LEVELS: usize> PartialOrd for State<LEVELS> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    let mut rooms = [[None; ROOMS]; 4];
+    for row in rooms.iter_mut() {
+        *row = parse_line(lines.next().context("not enough rooms").at(s)?)?;
     }
+
+    Ok(Position { hallway, rooms })
 }
 
-// Dijkstra's shortest path algorithm.
-
-// Start at `start` and use `dist` to track the current shortest distance
-// to each node. This implementation isn't memory-efficient as it may leave duplicate
-// nodes in the queue. It also uses `usize::MAX` as a sentinel value,
-// for a simpler implementation.
-fn shortest_path<const LEVELS: usize>(
-    start: Position<LEVELS>,
-    goal: Position<LEVELS>,
-) -> Option<usize> {
-    // dist[node] = current shortest distance from `start` to `node`
-    let mut dist = HashMap::new();
-
-    let mut heap = BinaryHeap::new();
-
-    // We're at `start`, with a zero cost
-    dist.insert(start.clone(), 0);
-    heap.push(State {
-        position: start,
-        cost: 0,
-    });
-
-    // Examine the frontier with lower cost nodes first (min-heap)
-    while let Some(State { cost, position }) = heap.pop() {
-        // dbg!(cost, &position);
-        // Alternatively we could have continued to find all shortest paths
-        if position == goal {
-            return Some(cost);
-        }
+impl Solution for Day23 {
+    type Input = (Row, Row);
+    type Part1 = usize;
+    type Part2 = usize;
 
-        // Important as we may have already found a better way
-        if let Some(&dist) = dist.get(&position) {
-            if cost > dist {
-                continue;
-            }
-        }
-
-        // For each node we can reach, see if we can find a way with
-        // a lower cost going through this node
-        for (next, edge_cost) in position.next() {
-            let next = State {
-                cost: cost + edge_cost,
-                position: next,
-            };
-
-            let better = match dist.get(&next.position) {
-                None => true,
-                Some(&dist) => next.cost < dist,
-            };
-            // If so, add it to the frontier and continue
-            if better {
-                heap.push(next.clone());
-                // Relaxation, we have now found a better way
-                dist.insert(next.position, next.cost);
-            }
-        }
+    fn parse(input: &str) -> Result<(Row, Row)> {
+        parse_input(input)
     }
 
-    // Goal not reachable
-    None
-}
-
-fn part1(input: &str) -> Result<usize> {
-    let state: Position<2> = input.parse()?;
-    let goal = Position {
-        hallway: [None; HALLS],
-        rooms: [[Some(0), Some(1), Some(2), Some(3)]; 2],
-    };
-    shortest_path(state, goal).context("no path")
-}
+    fn part1(rows: &(Row, Row)) -> Result<usize> {
+        let state: Position<2> = (*rows).into();
+        let goal = Position {
+            hallway: [None; HALLS],
+            rooms: [[Some(0), Some(1), Some(2), Some(3)]; 2],
+        };
+        search::dijkstra(state, Position::next, |p| *p == goal)
+            .map(|(cost, _)| cost)
+            .context("no path")
+    }
 
-fn part2(input: &str) -> Result<usize> {
-    let state: Position<4> = input.parse()?;
-    let goal = Position {
-        hallway: [None; HALLS],
-        rooms: [[Some(0), Some(1), Some(2), Some(3)]; 4],
-    };
+    fn part2(rows: &(Row, Row)) -> Result<usize> {
+        let state: Position<4> = (*rows).into();
+        let goal = Position {
+            hallway: [None; HALLS],
+            rooms: [[Some(0), Some(1), Some(2), Some(3)]; 4],
+        };
 
-    shortest_path(state, goal).context("no path")
+        search::dijkstra(state, Position::next, |p| *p == goal)
+            .map(|(cost, _)| cost)
+            .context("no path")
+    }
 }
 
 #[cfg(test)]
@@ -568,13 +483,13 @@ mod tests {
 
     #[test]
     fn test_part1() -> Result<()> {
-        assert_eq!(part1(TEST_INPUT)?, 12521);
+        assert_eq!(Day23::part1(&Day23::parse(TEST_INPUT)?)?, 12521);
         Ok(())
     }
 
     #[test]
     fn test_part2() -> Result<()> {
-        assert_eq!(part2(TEST_INPUT)?, 44169);
+        assert_eq!(Day23::part2(&Day23::parse(TEST_INPUT)?)?, 44169);
         Ok(())
     }
 }
@@ -0,0 +1,186 @@
+use crate::input::At;
+use crate::Solution;
+use anyhow::{anyhow, bail, Context, Result};
+
+pub struct Day24;
+
+const DIGITS: usize = 14;
+
+fn digits(mut num: i64) -> impl Iterator<Item = i64> {
+    let mut divisor = 1;
+    while num >= divisor * 10 {
+        divisor *= 10;
+    }
+
+    std::iter::from_fn(move || {
+        if divisor == 0 {
+            None
+        } else {
+            let v = num / divisor;
+            num %= divisor;
+            divisor /= 10;
+            Some(v)
+        }
+    })
+}
+
+/// The operand of `line`, which has to be `instruction` with a number
+fn param(line: &str, instruction: &str) -> Result<i64> {
+    let raw = line
+        .strip_prefix(instruction)
+        .with_context(|| format!("expected `{}`", instruction))
+        .at(line)?;
+    raw.parse().at(raw)
+}
+
+/// The three numbers that differ between the 14 blocks of 18 instructions, one per digit
+fn parse_params(s: &str) -> Result<Vec<(i64, i64, i64)>> {
+    let s = s.trim();
+    let lines: Vec<&str> = s.lines().collect();
+    if lines.len() != DIGITS * 18 {
+        return Err(anyhow!(
+            "expected {} instructions, got {}",
+            DIGITS * 18,
+            lines.len()
+        ))
+        .at(s);
+    }
+    let mut params = vec![];
+    for block in lines.chunks(18) {
+        let zdiv = param(block[4], "div z ")?;
+        let xadd = param(block[5], "add x ")?;
+        let yadd = param(block[15], "add y ")?;
+        if zdiv != 1 && zdiv != 26 {
+            return Err(anyhow!("expected to divide by 1 or 26")).at(block[4]);
+        }
+        for (line, value) in [(block[5], xadd), (block[15], yadd)] {
+            if value.abs() > 26 {
+                return Err(anyhow!("expected a number from -26 to 26")).at(line);
+            }
+        }
+        params.push((zdiv, xadd, yadd));
+    }
+    // the search assumes z is a stack that ends up empty
+    if params.iter().filter(|p| p.0 == 26).count() != DIGITS / 2 {
+        bail!("expected half the blocks to divide by 26");
+    }
+    Ok(params)
+}
+
+fn generic(input: i64, zprev: i64, zdiv: i64, xadd: i64, yadd: i64) -> Option<i64> {
+    let x = zprev % 26 + xadd;
+    let z = zprev / zdiv;
+    if input == x {
+        Some(z)
+    } else if zdiv > 1 {
+        None
+    } else {
+        Some(z * 26 + input + yadd)
+    }
+}
+
+fn generic_run(params: &[(i64, i64, i64)], n: i64) -> Option<i64> {
+    let mut param_iter = params.iter();
+    let inputs = digits(n);
+    let mut z = 0;
+    for input in inputs {
+        let &(zdiv, xadd, yadd) = param_iter.next()?;
+        z = generic(input, z, zdiv, xadd, yadd)?;
+    }
+    Some(z)
+}
+
+fn find(params: &[(i64, i64, i64)]) -> Result<Vec<i64>> {
+    let mut found = vec![];
+
+    'outer: for n in 10000000..100000000 {
+        for d in digits(n) {
+            if d == 0 {
+                continue 'outer;
+            }
+        }
+        if generic_run(params, n).is_some() {
+            found.push(n);
+        }
+    }
+    for _ in 0..6 {
+        let mut next = vec![];
+        for entry in found {
+            for n in 1..10 {
+                let val = entry * 10 + n;
+                if generic_run(params, val).is_some() {
+                    next.push(val);
+                }
+            }
+        }
+        found = next;
+    }
+    Ok(found)
+}
+
+impl Solution for Day24 {
+    /// Every valid model number
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(s: &str) -> Result<Vec<i64>> {
+        find(&parse_params(s)?)
+    }
+
+    fn part1(found: &Vec<i64>) -> Result<i64> {
+        found.iter().copied().max().context("no max")
+    }
+
+    fn part2(found: &Vec<i64>) -> Result<i64> {
+        found.iter().copied().min().context("no min")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn program(params: &[(i64, i64, i64)]) -> String {
+        params
+            .iter()
+            .map(|(zdiv, xadd, yadd)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                     mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                     mul y x\nadd z y\n",
+                    zdiv, xadd, yadd
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_params() -> Result<()> {
+        let params: Vec<_> = (0..DIGITS as i64)
+            .map(|i| if i % 2 == 0 { (1, 12, i) } else { (26, -i, 4) })
+            .collect();
+        assert_eq!(parse_params(&program(&params))?, params);
+
+        assert!(parse_params(&program(&params[1..])).is_err());
+        let mut bad = params.clone();
+        bad[3].0 = 0;
+        assert!(parse_params(&program(&bad)).is_err());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_params_malformed(
+            params in prop::collection::vec(any::<(i64, i64, i64)>(), DIGITS),
+            line in 0..DIGITS * 18,
+        ) {
+            let text = program(&params);
+            let _ = parse_params(&text);
+            let mut lines: Vec<&str> = text.lines().collect();
+            lines.remove(line);
+            prop_assert!(parse_params(&lines.join("\n")).is_err());
+        }
+    }
+}
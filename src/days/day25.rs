@@ -0,0 +1,193 @@
+use crate::coor::Coor;
+use crate::frames;
+use crate::grid::Grid;
+use crate::image::{self, Rgb};
+use crate::Solution;
+use anyhow::{bail, Error, Result};
+use std::fmt;
+use std::str::FromStr;
+
+pub struct Day25;
+
+/// Some herds never stop, like a row with a single gap
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Cell {
+    Empty,
+    Right,
+    Down,
+}
+
+impl Cell {
+    fn colour(&self) -> Rgb {
+        match self {
+            Cell::Empty => [0, 0, 64],
+            Cell::Right => [255, 160, 0],
+            Cell::Down => [0, 220, 220],
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Right => '>',
+            Cell::Down => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        Ok(match c {
+            '.' => Cell::Empty,
+            '>' => Cell::Right,
+            'v' => Cell::Down,
+            _ => bail!("invalid cell `{}`", c),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    cells: Grid<Cell>,
+}
+
+impl Map {
+    /// Move every cucumber in the `herd` facing `direction` at once, wrapping round the edges
+    fn step_herd(&mut self, herd: Cell, direction: Coor) -> bool {
+        let mut next = self.cells.clone();
+        let mut moved = false;
+        for (coor, &cell) in self.cells.iter() {
+            if cell == herd {
+                let dest = self.cells.wrap(coor + direction);
+                if self.cells[dest] == Cell::Empty {
+                    next[coor] = Cell::Empty;
+                    next[dest] = herd;
+                    moved = true;
+                }
+            }
+        }
+        self.cells = next;
+        moved
+    }
+
+    fn save_frame(&self) -> Result<()> {
+        frames::save("ppm", |out| {
+            image::write_ppm(out, &self.cells, Cell::colour)
+        })
+    }
+
+    fn step(&mut self) -> bool {
+        let right = self.step_herd(Cell::Right, Coor::new(1, 0));
+        let down = self.step_herd(Cell::Down, Coor::new(0, 1));
+        right || down
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Map { cells: s.parse()? })
+    }
+}
+
+impl Solution for Day25 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Map> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        let mut map = map.clone();
+        let mut count = 1;
+        while map.step() {
+            map.save_frame()?;
+            count += 1;
+            if count > MAX_STEPS {
+                bail!("still moving after {} steps", MAX_STEPS);
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(_map: &Map) -> Result<i32> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn test_step1() -> Result<()> {
+        let mut map: Map = "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v"
+            .parse()?;
+        map.step();
+        assert_eq!(
+            map.cells.to_string(),
+            ">.v.v>>..v
+v.v.>>vv..
+>v>.>.>.v.
+>>v>v.>v>.
+.>..v....v
+.>v>>.v.v.
+v....v>v>.
+.vv..>>v..
+v>.....vv."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_step2() -> Result<()> {
+        let mut map: Map = TEST_INPUT.parse()?;
+        map.step();
+        assert_eq!(
+            map.cells.to_string(),
+            "....>.>v.>
+v.v>.>v.v.
+>v>>..>v..
+>>v>v>.>.v
+.>v.v...v.
+v>>.>vvv..
+..v...>>..
+vv...>>vv.
+>.v.v..v.v"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        assert_eq!(Day25::part1(&Day25::parse(TEST_INPUT)?)?, 58);
+        Ok(())
+    }
+}
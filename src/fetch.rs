@@ -0,0 +1,192 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2021";
+
+/// Optional settings file, kept out of git since it holds the session token
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Where and how to download inputs from
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Config {
+    /// Read `session` and `base_url` from `path` if it exists, with `AOC_SESSION`
+    /// and `AOC_BASE_URL` from the environment taking precedence
+    pub fn load(path: &Path) -> Result<Config> {
+        Config::load_with(path, |name| env::var(name).ok())
+    }
+
+    /// Like `load`, with `env` looking up environment variables
+    pub fn load_with<F>(path: &Path, mut env: F) -> Result<Config>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut table = if path.exists() {
+            let raw = fs::read_to_string(path).context(format!("reading `{}`", path.display()))?;
+            raw.parse::<Table>()
+                .context(format!("invalid config file `{}`", path.display()))?
+        } else {
+            Table::new()
+        };
+        let mut setting = |env_name: &str, key: &str| -> Result<Option<String>> {
+            if let Some(value) = env(env_name) {
+                return Ok(Some(value));
+            }
+            Ok(match table.remove(key) {
+                Some(Value::String(value)) => Some(value),
+                Some(other) => bail!("expected a string for `{}`, got `{}`", key, other),
+                None => None,
+            })
+        };
+        Ok(Config {
+            base_url: setting("AOC_BASE_URL", "base_url")?
+                .unwrap_or_else(|| DEFAULT_BASE_URL.into()),
+            session: setting("AOC_SESSION", "session")?,
+        })
+    }
+}
+
+pub fn path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{:02}", day))
+}
+
+/// Whether `fetch` went to the network
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Download the input for `day` into `dir`, unless it is already there
+pub fn fetch(config: &Config, dir: &Path, day: u8) -> Result<(PathBuf, Fetched)> {
+    let path = path(dir, day);
+    if path.exists() {
+        return Ok((path, Fetched::Cached));
+    }
+    let session = config.session.as_ref().context(format!(
+        "no session token, set `AOC_SESSION` or `session` in `{}`",
+        CONFIG_FILE
+    ))?;
+
+    let url = format!(
+        "{}/day/{}/input",
+        config.base_url.trim_end_matches('/'),
+        day
+    );
+    let body = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .call()
+        .context(format!("downloading `{}`", url))?
+        .into_string()
+        .context(format!("reading `{}`", url))?;
+
+    // write to a temporary file first so an interrupted download is never mistaken for a cached one
+    fs::create_dir_all(dir)?;
+    let partial = path.with_extension("partial");
+    fs::write(&partial, body).context(format!("writing `{}`", partial.display()))?;
+    fs::rename(&partial, &path).context(format!("writing `{}`", path.display()))?;
+    Ok((path, Fetched::Downloaded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    /// Answers every request with `body`, counting how many it has seen
+    fn stub_server(body: &'static str) -> Result<(String, Arc<AtomicUsize>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut cookie = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if line.to_lowercase().starts_with("cookie:") {
+                        cookie = line;
+                    }
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = if request.starts_with("GET /day/5/input ")
+                    && cookie.contains("session=secret")
+                {
+                    ("200 OK", body)
+                } else {
+                    ("400 Bad Request", "")
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        Ok((url, requests))
+    }
+
+    #[test]
+    fn test_fetch_once() -> Result<()> {
+        let (base_url, requests) = stub_server("1,2\n3,4\n")?;
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let config = Config {
+            base_url,
+            session: Some("secret".into()),
+        };
+
+        let (cached, fetched) = fetch(&config, &dir, 5)?;
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&cached)?, "1,2\n3,4\n");
+
+        assert_eq!(fetch(&config, &dir, 5)?.1, Fetched::Cached);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // errors leave nothing behind in the cache
+        assert!(fetch(&config, &dir, 6).is_err());
+        assert!(!path(&dir, 6).exists());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_config() -> Result<()> {
+        let file = env::temp_dir().join(format!("aoc-config-{}.toml", std::process::id()));
+        fs::write(&file, "session = \"abc\"\n")?;
+        assert_eq!(
+            Config::load_with(&file, |_| None)?,
+            Config {
+                base_url: DEFAULT_BASE_URL.into(),
+                session: Some("abc".into())
+            }
+        );
+        // the environment wins over the file
+        let env = |name: &str| (name == "AOC_SESSION").then(|| "xyz".to_string());
+        assert_eq!(Config::load_with(&file, env)?.session, Some("xyz".into()));
+        fs::remove_file(&file)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+//! Saving one image per simulation step, for `aoc run --dump-frames DIR`. Days call `save`
+//! after each step; it does nothing unless the runner turned dumping on for this thread
+use crate::solution::Part;
+use anyhow::{Context, Result};
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+struct Dump {
+    dir: PathBuf,
+    day: u8,
+    part: Option<Part>,
+    count: usize,
+}
+
+thread_local! {
+    static DUMP: RefCell<Option<Dump>> = const { RefCell::new(None) };
+}
+
+/// Save frames from `day` into `dir` until `stop`, creating it if needed
+pub fn start(dir: &Path, day: u8) -> Result<()> {
+    fs::create_dir_all(dir).context(format!("creating `{}`", dir.display()))?;
+    DUMP.with(|dump| {
+        *dump.borrow_mut() = Some(Dump {
+            dir: dir.into(),
+            day,
+            part: None,
+            count: 0,
+        })
+    });
+    Ok(())
+}
+
+pub fn stop() {
+    DUMP.with(|dump| *dump.borrow_mut() = None);
+}
+
+/// Number frames from 0 again, in files named after `part`
+pub fn part(part: Part) {
+    DUMP.with(|dump| {
+        if let Some(dump) = dump.borrow_mut().as_mut() {
+            dump.part = Some(part);
+            dump.count = 0;
+        }
+    });
+}
+
+/// Write the next frame as `dayNN-partP-NNNNN.extension`, with `write` only called
+/// when dumping is on
+pub fn save<F>(extension: &str, write: F) -> Result<()>
+where
+    F: FnOnce(&mut dyn Write) -> std::io::Result<()>,
+{
+    DUMP.with(|dump| {
+        let mut dump = dump.borrow_mut();
+        let dump = match dump.as_mut() {
+            Some(dump) => dump,
+            None => return Ok(()),
+        };
+        let part = match dump.part {
+            Some(part) => format!("-part{}", part),
+            None => "".into(),
+        };
+        let path = dump.dir.join(format!(
+            "day{:02}{}-{:05}.{}",
+            dump.day, part, dump.count, extension
+        ));
+        let mut out =
+            BufWriter::new(File::create(&path).context(format!("creating `{}`", path.display()))?);
+        write(&mut out)
+            .and_then(|_| out.flush())
+            .context(format!("writing `{}`", path.display()))?;
+        dump.count += 1;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        // off by default
+        save("txt", |_| panic!("not dumping"))?;
+
+        start(&dir, 3)?;
+        part(Part::Part2);
+        save("txt", |out| out.write_all(b"one"))?;
+        save("txt", |out| out.write_all(b"two"))?;
+        stop();
+        save("txt", |_| panic!("not dumping"))?;
+
+        assert_eq!(fs::read(dir.join("day03-part2-00000.txt"))?, b"one");
+        assert_eq!(fs::read(dir.join("day03-part2-00001.txt"))?, b"two");
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
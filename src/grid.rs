@@ -0,0 +1,251 @@
+use crate::coor::Coor;
+use crate::input::{self, At};
+use anyhow::{anyhow, bail, Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangle of cells stored row by row, with `(0, 0)` in the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse one cell per char, with every line the same length
+    pub fn parse_with<F>(s: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        // only blank lines around the grid, as a space can be a cell
+        let s = s.trim_matches('\n');
+        if s.is_empty() {
+            bail!("empty grid");
+        }
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in s.lines() {
+            let before = cells.len();
+            for (raw, c) in input::chars(line) {
+                cells.push(cell(c).at(raw)?);
+            }
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(anyhow!("expected {} cells like the first line", width)).at(line)
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coor: Coor) -> bool {
+        (0..self.width as i64).contains(&coor.x()) && (0..self.height as i64).contains(&coor.y())
+    }
+
+    fn offset(&self, coor: Coor) -> Option<usize> {
+        if self.contains(coor) {
+            Some(coor.y() as usize * self.width + coor.x() as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, coor: Coor) -> Option<&T> {
+        self.offset(coor).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coor: Coor) -> Option<&mut T> {
+        self.offset(coor).map(move |i| &mut self.cells[i])
+    }
+
+    /// `coor` moved inside the grid, as if it repeated in every direction
+    pub fn wrap(&self, coor: Coor) -> Coor {
+        Coor::new(
+            coor.x().rem_euclid(self.width as i64),
+            coor.y().rem_euclid(self.height as i64),
+        )
+    }
+
+    /// Look up `coor` as if the grid repeated in every direction. Panics if the grid is empty
+    pub fn get_wrapping(&self, coor: Coor) -> &T {
+        &self[self.wrap(coor)]
+    }
+
+    /// Every coordinate, in reading order
+    pub fn coors(&self) -> impl Iterator<Item = Coor> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coor::new(x as i64, y as i64)))
+    }
+
+    /// Every cell with its coordinate, in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Coor, &T)> {
+        self.coors().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coor, &mut T)> {
+        self.coors().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The neighbours above, left, right and below `coor` that are inside the grid
+    pub fn orthogonal_neighbours(&self, coor: Coor) -> impl Iterator<Item = Coor> + '_ {
+        coor.orthogonal_neighbours().filter(|&n| self.contains(n))
+    }
+
+    /// The neighbours of `coor` that are inside the grid, diagonals included
+    pub fn all_neighbours(&self, coor: Coor) -> impl Iterator<Item = Coor> + '_ {
+        coor.all_neighbours().filter(|&n| self.contains(n))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coor> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `coor` is outside the grid, use `get` when it might be
+    fn index(&self, coor: Coor) -> &T {
+        match self.offset(coor) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside {}x{}", coor, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Coor> for Grid<T> {
+    fn index_mut(&mut self, coor: Coor) -> &mut T {
+        match self.offset(coor) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside {}x{}", coor, self.width, self.height),
+        }
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Error>,
+{
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_with(s, |c| T::try_from(c).map_err(Into::into))
+    }
+}
+
+/// One line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>> {
+        Grid::parse_with(s, |c| c.to_digit(10).ok_or_else(|| anyhow!("not a digit")))
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = digits("123\n456\n")?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Coor::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Coor::new(3, 1)), None);
+        assert_eq!(grid.get(Coor::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert!(digits("123\n45").is_err());
+        assert!(digits("12x").is_err());
+        assert!(digits("").is_err());
+
+        let chars: Grid<char> = "ab\ncd".parse()?;
+        assert_eq!(chars[Coor::new(1, 1)], 'd');
+
+        let spaced: Grid<char> = "\n .\n# \n".parse()?;
+        assert_eq!((spaced.width(), spaced.height()), (2, 2));
+        assert_eq!(spaced[Coor::new(0, 0)], ' ');
+        assert_eq!(spaced[Coor::new(1, 1)], ' ');
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrapping() -> Result<()> {
+        let grid = digits("12\n34")?;
+        assert_eq!(grid.get_wrapping(Coor::new(2, 0)), &1);
+        assert_eq!(grid.get_wrapping(Coor::new(-1, -1)), &4);
+        assert_eq!(grid.get_wrapping(Coor::new(5, 7)), &4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<()> {
+        let grid = digits("123\n456\n789")?;
+        let values = |coors: Vec<Coor>| coors.into_iter().map(|c| grid[c]).collect::<Vec<_>>();
+        let centre = Coor::new(1, 1);
+        assert_eq!(
+            values(grid.orthogonal_neighbours(centre).collect()),
+            vec![2, 4, 6, 8]
+        );
+        assert_eq!(
+            values(grid.all_neighbours(centre).collect()),
+            vec![1, 2, 3, 4, 6, 7, 8, 9]
+        );
+        assert_eq!(
+            values(grid.all_neighbours(Coor::new(0, 0)).collect()),
+            vec![2, 4, 5]
+        );
+        assert_eq!(
+            grid.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+            (1..=9).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
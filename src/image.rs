@@ -0,0 +1,103 @@
+//! Binary PBM, PGM and PPM images, which most image viewers and tools like ImageMagick
+//! or ffmpeg can read without us needing an encoder
+use crate::coor::{Coor, Rect};
+use crate::grid::Grid;
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+/// Black and white, one bit per pixel, with `on` pixels black
+pub fn write_pbm<W, T, F>(out: &mut W, grid: &Grid<T>, mut on: F) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    F: FnMut(&T) -> bool,
+{
+    write!(out, "P4\n{} {}\n", grid.width(), grid.height())?;
+    let cells: Vec<_> = grid.values().collect();
+    for row in cells.chunks(grid.width().max(1)) {
+        // each row starts on a new byte
+        let bytes: Vec<u8> = row
+            .chunks(8)
+            .map(|bits| {
+                bits.iter()
+                    .enumerate()
+                    .filter(|&(_, cell)| on(cell))
+                    .fold(0, |byte, (i, _)| byte | 0x80 >> i)
+            })
+            .collect();
+        out.write_all(&bytes)?;
+    }
+    Ok(())
+}
+
+/// Greyscale, with `level` from 0 for black to 255 for white
+pub fn write_pgm<W, T, F>(out: &mut W, grid: &Grid<T>, level: F) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    F: FnMut(&T) -> u8,
+{
+    write!(out, "P5\n{} {}\n255\n", grid.width(), grid.height())?;
+    out.write_all(&grid.values().map(level).collect::<Vec<_>>())
+}
+
+/// Colour, with `colour` picking each cell's red, green and blue
+pub fn write_ppm<W, T, F>(out: &mut W, grid: &Grid<T>, colour: F) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    F: FnMut(&T) -> Rgb,
+{
+    write!(out, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+    out.write_all(&grid.values().flat_map(colour).collect::<Vec<_>>())
+}
+
+/// The smallest grid covering `points`, with the points set, and where its top left is
+pub fn from_points(points: &HashSet<Coor>) -> (Grid<bool>, Coor) {
+    let area = match Rect::from_points(points.iter().copied()) {
+        Some(area) => area,
+        None => return (Grid::new(0, 0, false), Coor::new(0, 0)),
+    };
+    let mut grid = Grid::new(area.width() as usize, area.height() as usize, false);
+    for &point in points {
+        grid[point - area.min] = true;
+    }
+    (grid, area.min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_pbm() -> Result<()> {
+        let grid: Grid<char> = "#........#\n.#........".parse()?;
+        let mut out = vec![];
+        write_pbm(&mut out, &grid, |&c| c == '#')?;
+        assert_eq!(out, b"P4\n10 2\n\x80\x40\x40\x00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_pgm_ppm() -> Result<()> {
+        let grid: Grid<char> = "ab".parse()?;
+        let mut out = vec![];
+        write_pgm(&mut out, &grid, |&c| c as u8)?;
+        assert_eq!(out, b"P5\n2 1\n255\nab");
+
+        let mut out = vec![];
+        write_ppm(&mut out, &grid, |&c| [c as u8, 0, 255])?;
+        assert_eq!(out, b"P6\n2 1\n255\na\x00\xffb\x00\xff");
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [Coor::new(-1, 3), Coor::new(1, 4)].into_iter().collect();
+        let (grid, origin) = from_points(&points);
+        assert_eq!(origin, Coor::new(-1, 3));
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Coor::new(0, 0)] && grid[Coor::new(2, 1)]);
+        assert_eq!(grid.values().filter(|&&v| v).count(), 2);
+    }
+}
@@ -0,0 +1,147 @@
+use anyhow::{anyhow, Error, Result};
+use std::fmt;
+
+/// A parse error, together with the slice of the input it came from
+#[derive(Debug)]
+pub struct Located {
+    start: usize,
+    len: usize,
+    text: String,
+    source: Error,
+}
+
+impl fmt::Display for Located {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const MAX: usize = 40;
+        match self.text.char_indices().nth(MAX) {
+            Some((end, _)) => write!(f, "in `{}…`", &self.text[..end]),
+            None => write!(f, "in `{}`", self.text),
+        }
+    }
+}
+
+impl std::error::Error for Located {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Remember which part of the input a parser was looking at when it failed, so
+/// `locate` can point at it. `fragment` has to be a slice of the input, e.g. from
+/// `lines()` or `split()`, rather than a copy
+pub trait At<T> {
+    fn at(self, fragment: &str) -> Result<T>;
+}
+
+impl<T, E> At<T> for std::result::Result<T, E>
+where
+    E: Into<Error>,
+{
+    fn at(self, fragment: &str) -> Result<T> {
+        self.map_err(|e| {
+            Error::new(Located {
+                start: fragment.as_ptr() as usize,
+                len: fragment.len(),
+                text: fragment.into(),
+                source: e.into(),
+            })
+        })
+    }
+}
+
+/// Each char of `s` together with the slice it occupies, for pointing `at` a single char
+pub fn chars(s: &str) -> impl Iterator<Item = (&str, char)> {
+    s.char_indices()
+        .map(move |(i, c)| (&s[i..i + c.len_utf8()], c))
+}
+
+/// 1-based line and column of a fragment starting at address `fragment_start`, if that is inside `input`
+fn position(input: &str, fragment_start: usize) -> Option<(usize, usize)> {
+    let offset = fragment_start.checked_sub(input.as_ptr() as usize)?;
+    if offset > input.len() {
+        return None;
+    }
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Some((line, input[line_start..offset].chars().count() + 1))
+}
+
+/// The line with the error and a caret under the bad part, numbered like the input
+fn snippet(input: &str, line: usize, column: usize, len: usize) -> String {
+    let text = input.lines().nth(line - 1).unwrap_or("");
+    let width = line.to_string().len();
+    let carets = len.clamp(1, text.chars().count().saturating_sub(column - 1).max(1));
+    format!(
+        "{:width$} |\n{} | {}\n{:width$} | {}{}",
+        "",
+        line,
+        text,
+        "",
+        " ".repeat(column - 1),
+        "^".repeat(carets),
+    )
+}
+
+/// Turn an error from parsing `input` into one that says where it happened, with a
+/// snippet of the offending line. Errors that weren't marked with `at` are left alone
+pub fn locate(input: &str, error: Error) -> Error {
+    // the innermost marked fragment is the most precise one
+    let located = error
+        .chain()
+        .filter_map(|e| e.downcast_ref::<Located>())
+        .filter_map(|l| position(input, l.start).map(|pos| (l, pos)))
+        .last();
+    match located {
+        Some((located, (line, column))) => anyhow!(
+            "line {}, column {}: {:#}\n{}",
+            line,
+            column,
+            located.source,
+            snippet(input, line, column, located.len)
+        ),
+        None => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_coors(input: &str) -> Result<Vec<(i32, i32)>> {
+        input
+            .lines()
+            .map(|line| {
+                let (x, y) = line
+                    .split_once(',')
+                    .ok_or_else(|| anyhow!("no comma"))
+                    .at(line)?;
+                Ok((x.parse().at(x)?, y.parse().at(y)?))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "1,2\n3,4\n5 6\n";
+        let error = locate(input, parse_coors(input).unwrap_err());
+        assert_eq!(
+            format!("{:#}", error),
+            "line 3, column 1: no comma\n  |\n3 | 5 6\n  | ^^^"
+        );
+
+        let input = "1,2\n3,x4\n";
+        let error = locate(input, parse_coors(input).unwrap_err());
+        assert_eq!(
+            format!("{:#}", error),
+            "line 2, column 3: invalid digit found in string\n  |\n2 | 3,x4\n  |   ^^"
+        );
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let copy = String::from("5 6");
+        let error = Err::<(), _>(anyhow!("no comma")).at(&copy).unwrap_err();
+        assert_eq!(format!("{:#}", locate("5 6", error)), "in `5 6`: no comma");
+    }
+}
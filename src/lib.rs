@@ -1,6 +1,20 @@
+pub mod answers;
 pub mod coor;
 pub mod coor3;
 pub mod cpu;
+pub mod days;
+pub mod fetch;
+pub mod frames;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod point;
+pub mod pool;
+pub mod render;
+pub mod scaffold;
+pub mod search;
 // pub mod mod_arith;
 pub mod shared;
-pub use crate::shared::dispatch;
+pub mod solution;
+pub mod watch;
+pub use crate::solution::Solution;
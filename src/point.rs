@@ -0,0 +1,264 @@
+use crate::input::At;
+use anyhow::{anyhow, Error, Result};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point or vector with `D` integer components. `Coor` and `Coor3` are the 2D and 3D
+/// ones, which also name their components with `x()`, `y()` and `z()`
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point<const D: usize>(pub [i64; D]);
+
+impl<const D: usize> Point<D> {
+    fn zip_with<F: Fn(i64, i64) -> i64>(self, other: Self, f: F) -> Self {
+        let mut result = self;
+        for (a, b) in result.0.iter_mut().zip(other.0) {
+            *a = f(*a, b);
+        }
+        result
+    }
+
+    fn map<F: Fn(i64) -> i64>(self, f: F) -> Self {
+        Point(self.0.map(f))
+    }
+
+    fn checked_zip_with<F: Fn(i64, i64) -> Option<i64>>(self, other: Self, f: F) -> Option<Self> {
+        let mut result = self;
+        for (a, b) in result.0.iter_mut().zip(other.0) {
+            *a = f(*a, b)?;
+        }
+        Some(result)
+    }
+
+    /// `self + other`, or `None` if a component overflows
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_zip_with(other, i64::checked_add)
+    }
+
+    /// `self - other`, or `None` if a component overflows
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_zip_with(other, i64::checked_sub)
+    }
+
+    /// The smallest of each component
+    pub fn component_min(self, other: Self) -> Self {
+        self.zip_with(other, i64::min)
+    }
+
+    /// The largest of each component
+    pub fn component_max(self, other: Self) -> Self {
+        self.zip_with(other, i64::max)
+    }
+
+    /// -1, 0 or 1 for each component, i.e. a single step towards where this points
+    pub fn signum(self) -> Self {
+        self.map(i64::signum)
+    }
+
+    pub fn abs(self) -> Self {
+        self.map(i64::abs)
+    }
+
+    /// Distance from the origin moving along the axes
+    pub fn manhattan(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn manhattan_to(&self, other: Self) -> i64 {
+        (other - *self).manhattan()
+    }
+
+    /// `manhattan_to`, or `None` if it doesn't fit in an `i64`
+    pub fn checked_manhattan_to(&self, other: Self) -> Option<i64> {
+        other
+            .checked_sub(*self)?
+            .0
+            .iter()
+            .try_fold(0i64, |sum, c| sum.checked_add(c.checked_abs()?))
+    }
+
+    /// Distance moving like a king, diagonals included
+    pub fn chebyshev_to(&self, other: Self) -> i64 {
+        (other - *self).0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// Squared straight line distance, which keeps to integers and orders the same
+    pub fn euclidean_squared_to(&self, other: Self) -> i64 {
+        (other - *self).0.iter().map(|c| c * c).sum()
+    }
+}
+
+impl<const D: usize> Default for Point<D> {
+    fn default() -> Self {
+        Point([0; D])
+    }
+}
+
+impl<const D: usize> fmt::Debug for Point<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// `D` comma separated integers
+impl<const D: usize> FromStr for Point<D> {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        let raw: Vec<&str> = s.split(',').collect();
+        if raw.len() != D {
+            return Err(anyhow!("expected {} comma separated values", D)).at(s);
+        }
+        let mut point = Point::default();
+        for (c, raw) in point.0.iter_mut().zip(raw) {
+            *c = raw.parse().at(raw)?;
+        }
+        Ok(point)
+    }
+}
+
+impl<const D: usize> From<[i64; D]> for Point<D> {
+    fn from(components: [i64; D]) -> Self {
+        Point(components)
+    }
+}
+
+impl<const D: usize> Index<usize> for Point<D> {
+    type Output = i64;
+    fn index(&self, i: usize) -> &i64 {
+        &self.0[i]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for Point<D> {
+    fn index_mut(&mut self, i: usize) -> &mut i64 {
+        &mut self.0[i]
+    }
+}
+
+impl<const D: usize> Add for Point<D> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl<const D: usize> AddAssign for Point<D> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const D: usize> Sub for Point<D> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl<const D: usize> SubAssign for Point<D> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const D: usize> Neg for Point<D> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|c| -c)
+    }
+}
+
+impl<const D: usize> Mul<i64> for Point<D> {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const D: usize> MulAssign<i64> for Point<D> {
+    fn mul_assign(&mut self, rhs: i64) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const D: usize> Mul<Point<D>> for i64 {
+    type Output = Point<D>;
+    fn mul(self, rhs: Point<D>) -> Point<D> {
+        rhs * self
+    }
+}
+
+/// Rounds towards zero, like integer division
+impl<const D: usize> Div<i64> for Point<D> {
+    type Output = Self;
+    fn div(self, rhs: i64) -> Self {
+        self.map(|c| c / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point4 = Point<4>;
+
+    #[test]
+    fn test_ops() {
+        let a = Point4::from([1, -2, 3, 0]);
+        let b = Point4::from([2, 2, -5, 7]);
+        assert_eq!(a + b, Point([3, 0, -2, 7]));
+        assert_eq!(a - b, Point([-1, -4, 8, -7]));
+        assert_eq!(-a, Point([-1, 2, -3, 0]));
+        assert_eq!(a * 2, 2 * a);
+        assert_eq!(b / 2, Point([1, 1, -2, 3]));
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3;
+        assert_eq!(c, b * 3);
+        assert_eq!(a.component_min(b), Point([1, -2, -5, 0]));
+        assert_eq!(a.component_max(b), Point([2, 2, 3, 7]));
+        assert_eq!(b.signum(), Point([1, 1, -1, 1]));
+        assert_eq!(a[2], 3);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point4::from([1, -2, 3, 0]);
+        let b = Point4::from([2, 2, -5, 7]);
+        assert_eq!(a.manhattan(), 6);
+        assert_eq!(a.manhattan_to(b), 20);
+        assert_eq!(a.chebyshev_to(b), 8);
+        assert_eq!(a.euclidean_squared_to(b), 1 + 16 + 64 + 49);
+        assert_eq!(a.checked_manhattan_to(b), Some(20));
+    }
+
+    #[test]
+    fn test_checked() {
+        let a = Point4::from([1, -2, 3, 0]);
+        let b = Point4::from([2, 2, -5, 7]);
+        assert_eq!(a.checked_add(b), Some(a + b));
+        assert_eq!(a.checked_sub(b), Some(a - b));
+        let max = Point4::from([i64::MAX, 0, 0, 0]);
+        assert_eq!(max.checked_add(a), None);
+        assert_eq!(max.checked_sub(-a), None);
+        assert_eq!(max.checked_manhattan_to(-max), None);
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!("1,-2,3,0".parse::<Point4>()?, Point([1, -2, 3, 0]));
+        assert!("1,2,3".parse::<Point4>().is_err());
+        assert!("1,2,3,4,5".parse::<Point4>().is_err());
+        assert!("1,2,x,4".parse::<Point4>().is_err());
+        assert_eq!(format!("{:?}", Point([1, -2, 3])), "(1, -2, 3)");
+        Ok(())
+    }
+}
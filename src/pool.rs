@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Result};
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+/// Run a task on its own thread, so a panic or a timeout becomes an error. A timeout only
+/// stops the waiting: threads can't be cancelled, so the task keeps using a CPU in the
+/// background until it finishes or the process exits
+fn run_one<T: Send + 'static>(task: Task<T>, timeout: Option<Duration>) -> Result<T> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        // the receiver is gone if we timed out
+        let _ = tx.send(task());
+    });
+    match timeout {
+        Some(timeout) => rx.recv_timeout(timeout).map_err(|e| match e {
+            RecvTimeoutError::Timeout => anyhow!("timed out after {:?}", timeout),
+            RecvTimeoutError::Disconnected => anyhow!("panicked"),
+        }),
+        None => rx.recv().map_err(|_| anyhow!("panicked")),
+    }
+}
+
+/// Run `tasks` on `jobs` worker threads, returning the results in the same order as the tasks
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Result<T>> {
+    let count = tasks.len();
+    let queue = Arc::new(Mutex::new(
+        tasks.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (tx, rx) = mpsc::channel();
+    for _ in 0..jobs.max(1) {
+        let queue = queue.clone();
+        let tx = tx.clone();
+        thread::spawn(move || loop {
+            let next = queue.lock().unwrap().pop_front();
+            match next {
+                Some((i, task)) => tx.send((i, run_one(task, timeout))).unwrap(),
+                None => break,
+            }
+        });
+    }
+    drop(tx);
+
+    let mut results: Vec<Option<Result<T>>> = (0..count).map(|_| None).collect();
+    for (i, result) in rx {
+        results[i] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("every task sends a result"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let tasks: Vec<Task<u64>> = (0..20)
+            .map(|i| -> Task<u64> {
+                Box::new(move || {
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * i
+                })
+            })
+            .collect();
+        let results: Vec<u64> = run(tasks, 4, None).into_iter().flatten().collect();
+        assert_eq!(results, (0..20).map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_timeout_and_panic() {
+        let tasks: Vec<Task<u64>> = vec![
+            Box::new(|| 1),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(5));
+                2
+            }),
+            Box::new(|| panic!("runaway")),
+            Box::new(|| 4),
+        ];
+        let results = run(tasks, 2, Some(Duration::from_millis(200)));
+        assert_eq!(results[0].as_ref().unwrap(), &1);
+        assert!(format!("{}", results[1].as_ref().unwrap_err()).starts_with("timed out"));
+        assert_eq!(format!("{}", results[2].as_ref().unwrap_err()), "panicked");
+        assert_eq!(results[3].as_ref().unwrap(), &4);
+    }
+}
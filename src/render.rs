@@ -0,0 +1,145 @@
+use crate::coor::{Coor, Rect};
+use crate::grid::Grid;
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+
+/// Width of a letter in the font, not counting the blank column after it
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+
+/// The capital letters of the block font used by puzzles that draw their answer. `Y` is
+/// 5 wide, leaving no gap before the next letter, so it isn't here and can't be read
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Draw `area` one row per line, each line ending in a newline
+pub fn render<F: FnMut(Coor) -> char>(area: Rect, mut cell: F) -> String {
+    let mut output = String::new();
+    for coor in area.points() {
+        output.push(cell(coor));
+        if coor.x() == area.max.x() {
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Draw the smallest rectangle covering `points`, with `#` for each point and `.` elsewhere
+pub fn points(points: &HashSet<Coor>) -> String {
+    match Rect::from_points(points.iter().copied()) {
+        Some(area) => render(area, |c| if points.contains(&c) { '#' } else { '.' }),
+        None => String::new(),
+    }
+}
+
+/// Draw every cell of `grid` as the char `cell` picks for it
+pub fn grid<T, F: FnMut(&T) -> char>(grid: &Grid<T>, mut cell: F) -> String {
+    let area = Rect::new(
+        Coor::new(0, 0),
+        Coor::new(grid.width() as i64 - 1, grid.height() as i64 - 1),
+    );
+    render(area, |c| cell(&grid[c]))
+}
+
+/// Read the block letters drawn in `text` with `#`, which has to be 6 lines high with
+/// the first letter starting in the first column. Letters are 4 wide with a gap of 1
+pub fn ocr(text: &str) -> Result<String> {
+    let rows: Vec<Vec<bool>> = text
+        .trim_end_matches('\n')
+        .lines()
+        .map(|l| l.chars().map(|c| c == '#').collect())
+        .collect();
+    if rows.len() != LETTER_HEIGHT {
+        bail!(
+            "expected {} rows of letters, got {}",
+            LETTER_HEIGHT,
+            rows.len()
+        );
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let mut letters = String::new();
+    for start in (0..width).step_by(LETTER_WIDTH + 1) {
+        let glyph: String = (0..LETTER_HEIGHT)
+            .flat_map(|y| (start..start + LETTER_WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| if lit(x, y) { '#' } else { '.' })
+            .collect();
+        match FONT.iter().find(|(_, g)| *g == glyph) {
+            Some(&(letter, _)) => letters.push(letter),
+            None => bail!("unrecognised letter at column {}", start),
+        }
+    }
+    if letters.is_empty() {
+        bail!("no letters");
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let dots = [Coor::new(1, 1), Coor::new(3, 2)].into_iter().collect();
+        assert_eq!(points(&dots), "#..\n..#\n");
+        assert_eq!(points(&HashSet::new()), "");
+    }
+
+    #[test]
+    fn test_grid() -> Result<()> {
+        let g: Grid<char> = "ab\ncd".parse()?;
+        assert_eq!(grid(&g, |c| c.to_ascii_uppercase()), "AB\nCD\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_ocr() -> Result<()> {
+        let text = "\
+#..#.####.###..#....
+#..#.#....#..#.#....
+####.###..###..#....
+#..#.#....#..#.#....
+#..#.#....#..#.#....
+#..#.####.###..####.
+";
+        assert_eq!(ocr(text)?, "HEBL");
+        // the trailing gap is optional
+        assert_eq!(ocr(text.replace(".\n", "\n").as_str())?, "HEBL");
+        assert!(ocr("#..#\n#..#\n").is_err());
+        assert!(ocr("#####\n#   #\n#   #\n#   #\n#####\n").is_err());
+        let y = "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#..\n";
+        assert!(ocr(y).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_font() {
+        for (letter, glyph) in FONT {
+            assert_eq!(glyph.len(), LETTER_WIDTH * LETTER_HEIGHT, "{}", letter);
+            let text: String = glyph
+                .as_bytes()
+                .chunks(LETTER_WIDTH)
+                .map(|row| format!("{}\n", std::str::from_utf8(row).unwrap()))
+                .collect();
+            assert_eq!(ocr(&text).unwrap(), letter.to_string());
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../day.tpl");
+
+/// The example answers and input to bake into a new day's tests
+#[derive(Debug, Default, Clone)]
+pub struct Example {
+    pub input: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Return type, value and placeholder return value for an example answer
+fn answer_literal(answer: Option<&str>) -> (&'static str, String, &'static str) {
+    match answer {
+        None => ("i32", "0".into(), "0"),
+        Some(answer) if answer.parse::<i32>().is_ok() => ("i32", answer.into(), "0"),
+        Some(answer) if answer.parse::<i64>().is_ok() => ("i64", answer.into(), "0"),
+        Some(answer) => ("String", format!("{:?}", answer), "String::new()"),
+    }
+}
+
+/// A multi-line string literal in the style of the existing tests
+fn string_literal(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.trim_end().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+pub fn render(day: u8, example: &Example) -> String {
+    let (part1_type, part1, part1_default) = answer_literal(example.part1.as_deref());
+    let (part2_type, part2, part2_default) = answer_literal(example.part2.as_deref());
+    TEMPLATE
+        .replace("DayNN", &format!("Day{:02}", day))
+        .replace("{part1_type}", part1_type)
+        .replace("{part2_type}", part2_type)
+        .replace("{part1_default}", part1_default)
+        .replace("{part2_default}", part2_default)
+        .replace("{part1}", &part1)
+        .replace("{part2}", &part2)
+        .replace(
+            "{test_input}",
+            &string_literal(example.input.as_deref().unwrap_or("")),
+        )
+}
+
+/// Insert `line` among the consecutive lines starting with `prefix`, keeping them sorted
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    if lines.contains(&line) {
+        return Ok(source.into());
+    }
+    let first = lines
+        .iter()
+        .position(|l| l.starts_with(prefix))
+        .context(format!("no `{}` lines found", prefix))?;
+    let pos = lines[first..]
+        .iter()
+        .position(|l| !l.starts_with(prefix) || *l > line)
+        .map_or(lines.len(), |offset| first + offset);
+    lines.insert(pos, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add `&DayNN` to the `DAYS` array, wrapped at 100 columns like rustfmt
+fn insert_into_days(source: &str, day: u8) -> Result<String> {
+    let start = source
+        .find("pub const DAYS: [&dyn Solver; ")
+        .context("no `DAYS` array found")?;
+    let open = start
+        + source[start..]
+            .find("= [")
+            .context("no `DAYS` array found")?
+        + 3;
+    let close = open + source[open..].find("];").context("no `DAYS` array found")?;
+
+    let mut entries: Vec<String> = source[open..close]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(String::from)
+        .collect();
+    let entry = format!("&Day{:02}", day);
+    if !entries.contains(&entry) {
+        entries.push(entry);
+    }
+    entries.sort();
+
+    let mut body = String::from("\n");
+    let mut line = String::from("   ");
+    for entry in &entries {
+        if line.len() + entry.len() + 2 > 100 {
+            body.push_str(&line);
+            body.push('\n');
+            line = String::from("   ");
+        }
+        line.push(' ');
+        line.push_str(entry);
+        line.push(',');
+    }
+    body.push_str(&line);
+    body.push('\n');
+
+    Ok(format!(
+        "{}pub const DAYS: [&dyn Solver; {}] = [{}{}",
+        &source[..start],
+        entries.len(),
+        body,
+        &source[close..]
+    ))
+}
+
+/// Add `day` to the registry in `src/days/mod.rs`. Already registered days are left alone
+pub fn register(source: &str, day: u8) -> Result<String> {
+    let source = insert_sorted(source, "mod day", &format!("mod day{:02};", day))?;
+    let source = insert_sorted(
+        &source,
+        "pub use day",
+        &format!("pub use day{:02}::Day{:02};", day, day),
+    )?;
+    insert_into_days(&source, day)
+}
+
+/// Write `src/days/dayNN.rs` under `root` and register it, refusing to overwrite
+/// an existing day unless `force` is set
+pub fn new_day(root: &Path, day: u8, example: &Example, force: bool) -> Result<PathBuf> {
+    let path = root.join(format!("src/days/day{:02}.rs", day));
+    if path.exists() && !force {
+        bail!(
+            "`{}` already exists, use `--force` to overwrite it",
+            path.display()
+        );
+    }
+    let registry = root.join("src/days/mod.rs");
+    let source =
+        fs::read_to_string(&registry).context(format!("reading `{}`", registry.display()))?;
+    let registered = register(&source, day)?;
+
+    fs::write(&path, render(day, example)).context(format!("writing `{}`", path.display()))?;
+    fs::write(&registry, registered).context(format!("writing `{}`", registry.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::solution::Solver;
+
+mod day01;
+mod day03;
+
+pub use day01::Day01;
+pub use day03::Day03;
+
+/// Every day's solution, indexed by `day - 1`
+pub const DAYS: [&dyn Solver; 2] = [&Day01, &Day03];
+";
+
+    #[test]
+    fn test_register() -> Result<()> {
+        assert_eq!(
+            register(REGISTRY, 2)?,
+            "use crate::solution::Solver;
+
+mod day01;
+mod day02;
+mod day03;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+
+/// Every day's solution, indexed by `day - 1`
+pub const DAYS: [&dyn Solver; 3] = [
+    &Day01, &Day02, &Day03,
+];
+"
+        );
+        let registry = include_str!("days/mod.rs");
+        assert_eq!(register(registry, 7)?, registry);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() {
+        let example = Example {
+            input: Some("a \"b\"\nc\n".into()),
+            part1: Some("17".into()),
+            part2: Some("ABC".into()),
+        };
+        let rendered = render(5, &example);
+        assert!(rendered.contains("pub struct Day05;"));
+        assert!(rendered.contains("const TEST_INPUT: &str = \"a \\\"b\\\"\nc\";"));
+        assert!(rendered.contains("type Part2 = String;"));
+        assert!(rendered.contains("assert_eq!(Day05::part1(&Day05::parse(TEST_INPUT)?)?, 17);"));
+        assert!(
+            rendered.contains("assert_eq!(Day05::part2(&Day05::parse(TEST_INPUT)?)?, \"ABC\");")
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Graph searches over a `successors` closure, so puzzles only need to say where each
+//! node leads. Every search returns the cost of what it found with the path there, start
+//! and goal included
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Follow `parent` links back from `last` to the start
+fn path<N: Clone>(nodes: &[(N, Option<usize>)], last: usize) -> Vec<N> {
+    let mut path = vec![];
+    let mut at = Some(last);
+    while let Some(i) = at {
+        path.push(nodes[i].0.clone());
+        at = nodes[i].1;
+    }
+    path.reverse();
+    path
+}
+
+/// The cheapest path to a goal, with each successor coming with the cost of the step to it
+pub fn dijkstra<N, C, FN, IN, FG>(start: N, successors: FN, is_goal: FG) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, but looking first where `heuristic` guesses a goal is closest. The
+/// guess must never be more than the real remaining cost, or the path may not be cheapest
+pub fn astar<N, C, FN, IN, FH, FG>(
+    start: N,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(C, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    // every node reached with where it was reached from, and the cheapest cost so far
+    let mut nodes = vec![(start.clone(), None)];
+    let mut costs = vec![C::default()];
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // a cheaper way here was found after this was queued
+        if cost > costs[i] {
+            continue;
+        }
+        let node = nodes[i].0.clone();
+        if is_goal(&node) {
+            return Some((cost, path(&nodes, i)));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let j = match index.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if next_cost >= costs[j] {
+                        continue;
+                    }
+                    nodes[j].1 = Some(i);
+                    costs[j] = next_cost;
+                    j
+                }
+                Entry::Vacant(e) => {
+                    nodes.push((e.key().clone(), Some(i)));
+                    costs.push(next_cost);
+                    *e.insert(nodes.len() - 1)
+                }
+            };
+            heap.push(Reverse((next_cost + heuristic(&nodes[j].0), next_cost, j)));
+        }
+    }
+    None
+}
+
+/// The path to a goal with the fewest steps, and how many steps that is
+pub fn bfs<N, FN, IN, FG>(start: N, mut successors: FN, mut is_goal: FG) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![(start.clone(), None)];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let node = nodes[i].0.clone();
+        if is_goal(&node) {
+            let path = path(&nodes, i);
+            return Some((path.len() - 1, path));
+        }
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                nodes.push((next, Some(i)));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+/// Any path to a goal, going as deep as possible first, and how many steps it is. The
+/// path is not necessarily the shortest
+pub fn dfs<N, FN, IN, FG>(start: N, mut successors: FN, mut is_goal: FG) -> Option<(usize, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![(start, None)];
+    let mut seen = HashSet::new();
+    let mut stack = vec![0];
+    while let Some(i) = stack.pop() {
+        let node = nodes[i].0.clone();
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            let path = path(&nodes, i);
+            return Some((path.len() - 1, path));
+        }
+        for next in successors(&node) {
+            if !seen.contains(&next) {
+                nodes.push((next, Some(i)));
+                stack.push(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -1-> c -1-> d, with a shortcut a -5-> d and a dead end b -1-> e
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1), ('e', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    fn steps(node: &char) -> Vec<char> {
+        edges(node).into_iter().map(|(n, _)| n).collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra('a', edges, |&n| n == 'd'),
+            Some((3, vec!['a', 'b', 'c', 'd']))
+        );
+        assert_eq!(dijkstra('a', edges, |&n| n == 'a'), Some((0, vec!['a'])));
+        assert_eq!(dijkstra('a', edges, |&n| n == 'z'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // remaining cost, never too high
+        let heuristic = |n: &char| match n {
+            'a' => 3,
+            'b' => 2,
+            'c' => 1,
+            _ => 0,
+        };
+        assert_eq!(
+            astar('a', edges, heuristic, |&n| n == 'd'),
+            Some((3, vec!['a', 'b', 'c', 'd']))
+        );
+    }
+
+    #[test]
+    fn test_bfs_dfs() {
+        assert_eq!(bfs('a', steps, |&n| n == 'd'), Some((1, vec!['a', 'd'])));
+        assert_eq!(
+            bfs('a', steps, |&n| n == 'e'),
+            Some((2, vec!['a', 'b', 'e']))
+        );
+        assert_eq!(bfs('c', steps, |&n| n == 'a'), None);
+        // takes the last successor first
+        assert_eq!(dfs('a', steps, |&n| n == 'd'), Some((1, vec!['a', 'd'])));
+        assert_eq!(
+            dfs('a', steps, |&n| n == 'e'),
+            Some((2, vec!['a', 'b', 'e']))
+        );
+        assert_eq!(dfs('c', steps, |&n| n == 'a'), None);
+    }
+}
@@ -1,24 +1,26 @@
-use anyhow::{bail, Context, Result};
-use clap::{App, Arg};
-use std::fmt::Display;
+use crate::answers::{self, Check};
+use crate::cpu::{Cpu, CpuState};
+use crate::days;
+use crate::fetch;
+use crate::frames;
+use crate::pool;
+use crate::scaffold;
+use crate::solution::{Parsed, Part, Solver, Timed};
+use crate::watch;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use serde_json::json;
 use std::fs::File;
 use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-enum Part {
-    Part1,
-    Part2,
-}
-
+#[derive(Clone)]
 enum Source {
     Stdin,
     File(String),
 }
 
-struct Args {
-    part: Part,
-    source: Source,
-}
-
 fn read_stdin() -> Result<String> {
     let mut buffer = String::new();
     io::stdin().read_to_string(&mut buffer)?;
@@ -33,67 +35,682 @@ fn read_file(filename: &str) -> Result<String> {
     Ok(buffer)
 }
 
-fn parse_input() -> Result<Args> {
-    let matches = App::new("adventofcode")
-        .arg(
-            Arg::with_name("part")
-                .short("p")
-                .takes_value(true)
-                .default_value("1")
-                .possible_values(&["1", "2"]),
-        )
-        .arg(
-            Arg::with_name("input")
-                .help("Sets the input file to use, or `-` for stdin. Leave out to assume `input/<binary name>`")
-                .index(1),
-        )
-        .get_matches();
+/// `1`, `2` or `both`
+fn parse_parts(s: &str) -> Result<Vec<Part>> {
+    Ok(match s {
+        "1" => vec![Part::Part1],
+        "2" => vec![Part::Part2],
+        "both" => vec![Part::Part1, Part::Part2],
+        _ => bail!("Invalid part `{}`", s),
+    })
+}
 
-    let part = match matches.value_of("part").unwrap_or("1") {
-        "1" => Part::Part1,
-        "2" => Part::Part2,
-        _ => bail!("Invalid part"),
-    };
-    let source = match matches.value_of("input") {
-        Some("-") => Source::Stdin,
-        Some(filename) => Source::File(filename.into()),
-        None => {
-            let filename: String = std::env::current_exe()?
-                .file_name()
-                .context("invalid current exe filename")?
-                .to_str()
-                .context("current exe filename not a str?")?
-                .into();
-            Source::File(format!("input/{}", filename))
+/// Parse a day selection: a single day `5`, a range `3..9` or `3..=9`, or `all`
+pub fn parse_days(s: &str) -> Result<Vec<u8>> {
+    let last = days::DAYS.len() as u8;
+    let parse_day = |raw: &str| -> Result<u8> {
+        let day: u8 = raw.parse().context(format!("invalid day `{}`", raw))?;
+        if day == 0 || day > last {
+            bail!("no such day `{}`", day);
         }
+        Ok(day)
     };
-    Ok(Args { part, source })
+    let days = if s == "all" {
+        (1..=last).collect()
+    } else if let Some((start, end)) = s.split_once("..=") {
+        (parse_day(start)?..=parse_day(end)?).collect()
+    } else if let Some((start, end)) = s.split_once("..") {
+        (parse_day(start)?..parse_day(end)?).collect()
+    } else {
+        vec![parse_day(s)?]
+    };
+    Ok(days)
+}
+
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    /// One entry per run, so more than one when benchmarking
+    pub result: Result<Vec<Timed>>,
+    /// Only filled in with `--check`
+    pub check: Option<Check>,
 }
 
-type DayFunc<T> = fn(&str) -> Result<T>;
+impl Row {
+    /// Including details such as input snippets, which can span several lines
+    fn full_status(&self) -> String {
+        match (&self.result, &self.check) {
+            (Err(e), _) => format!("error: {:#}", e),
+            (Ok(_), None) => "ok".into(),
+            (Ok(_), Some(Check::Correct)) => "correct".into(),
+            (Ok(_), Some(Check::Wrong { .. })) => "wrong".into(),
+            (Ok(_), Some(Check::Unknown)) => "no expected answer".into(),
+        }
+    }
+
+    /// Just the first line, the details are printed below the table
+    fn status(&self) -> String {
+        self.full_status().lines().next().unwrap_or("").into()
+    }
+
+    fn failed(&self) -> bool {
+        self.result.is_err() || matches!(self.check, Some(Check::Wrong { .. }))
+    }
+
+    /// What went wrong, without the `error: ` prefix the table shows
+    fn error(&self) -> Option<String> {
+        match &self.result {
+            Err(e) => Some(format!("{:#}", e)),
+            Ok(_) if self.failed() => Some(self.full_status()),
+            Ok(_) => None,
+        }
+    }
+}
+
+/// A day's parsed input, with how long each parse took
+type ParseRuns = (Parsed, Vec<Duration>);
 
-fn run<S, T>(part1: &DayFunc<S>, part2: &DayFunc<T>) -> Result<String>
-where
-    S: Display,
-    T: Display,
-{
-    let args = parse_input()?;
-    let input = match args.source {
+/// A part's answer on each run, with how long it took
+type SolveRuns = Vec<(String, Duration)>;
+
+/// Read and parse a day's input, `runs` times when benchmarking, keeping the last parse
+fn parse_day(solver: &dyn Solver, source: &Source, runs: usize) -> Result<ParseRuns> {
+    let input = match source {
         Source::Stdin => read_stdin(),
-        Source::File(filename) => read_file(&filename),
+        Source::File(filename) => read_file(filename).context(format!("reading `{}`", filename)),
     }?;
-    match args.part {
-        Part::Part1 => part1(&input).map(|res| format!("{}", res)),
-        Part::Part2 => part2(&input).map(|res| format!("{}", res)),
+    let mut parsed = None;
+    let mut times = vec![];
+    for _ in 0..runs {
+        let start = Instant::now();
+        parsed = Some(solver.parse(&input)?);
+        times.push(start.elapsed());
+    }
+    Ok((parsed.context("no runs")?, times))
+}
+
+/// Solve `part` `runs` times, with each answer and how long it took
+fn solve_part(
+    day: u8,
+    solver: &dyn Solver,
+    parsed: &Parsed,
+    part: Part,
+    runs: usize,
+    frames_dir: Option<&Path>,
+) -> Result<SolveRuns> {
+    if let Some(dir) = frames_dir {
+        frames::start(dir, day)?;
+    }
+    let solved = (0..runs)
+        .map(|_| {
+            frames::part(part);
+            let start = Instant::now();
+            let answer = solver.solve_part(parsed, part)?;
+            Ok((answer, start.elapsed()))
+        })
+        .collect();
+    frames::stop();
+    solved
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .filter_map(|row| row.result.as_ref().ok())
+        .flat_map(|runs| runs[0].answer.lines())
+        .map(str::len)
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or(0);
+    println!(
+        "day  part  {:answer_width$}  {:>10}  {:>10}  status",
+        "answer", "parse", "solve"
+    );
+    for row in rows {
+        let (answer, parse, solve) = match &row.result {
+            Ok(runs) => (
+                runs[0].answer.as_str(),
+                format!("{:.1?}", runs[0].parse),
+                format!("{:.1?}", runs[0].solve),
+            ),
+            Err(_) => ("", "".into(), "".into()),
+        };
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or("");
+        println!(
+            "{:>3}  {:>4}  {:answer_width$}  {:>10}  {:>10}  {}",
+            row.day,
+            row.part,
+            first,
+            parse,
+            solve,
+            row.status()
+        );
+        for line in lines {
+            println!("{:>3}  {:>4}  {}", "", "", line);
+        }
+    }
+}
+
+/// Min, median and max
+fn stats(durations: impl Iterator<Item = Duration>) -> (Duration, Duration, Duration) {
+    let mut durations: Vec<_> = durations.collect();
+    durations.sort_unstable();
+    (
+        durations[0],
+        durations[durations.len() / 2],
+        durations[durations.len() - 1],
+    )
+}
+
+/// Tab separated with times in nanoseconds, so results can be diffed between commits
+fn print_bench(rows: &[Row]) {
+    println!("day\tpart\truns\tparse_min\tparse_median\tparse_max\tsolve_min\tsolve_median\tsolve_max\tstatus");
+    for row in rows {
+        match &row.result {
+            Ok(runs) => {
+                let (parse_min, parse_median, parse_max) = stats(runs.iter().map(|t| t.parse));
+                let (solve_min, solve_median, solve_max) = stats(runs.iter().map(|t| t.solve));
+                println!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    row.day,
+                    row.part,
+                    runs.len(),
+                    parse_min.as_nanos(),
+                    parse_median.as_nanos(),
+                    parse_max.as_nanos(),
+                    solve_min.as_nanos(),
+                    solve_median.as_nanos(),
+                    solve_max.as_nanos(),
+                    row.status(),
+                );
+            }
+            Err(_) => println!(
+                "{}\t{}\t0\t\t\t\t\t\t\t{}",
+                row.day,
+                row.part,
+                row.status().replace(['\t', '\n'], " ")
+            ),
+        }
+    }
+}
+
+/// `{day, part, answer, duration_ns, error}`, with the median duration when benchmarking
+fn to_json(row: &Row) -> serde_json::Value {
+    let (answer, duration_ns) = match &row.result {
+        Ok(runs) => {
+            let (_, median, _) = stats(runs.iter().map(|t| t.parse + t.solve));
+            (
+                Some(runs[0].answer.as_str()),
+                Some(median.as_nanos() as u64),
+            )
+        }
+        Err(_) => (None, None),
+    };
+    json!({
+        "day": row.day,
+        "part": match row.part {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        },
+        "answer": answer,
+        "duration_ns": duration_ns,
+        "error": row.error(),
+    })
+}
+
+/// One JSON object per line
+fn print_json(rows: &[Row]) {
+    for row in rows {
+        println!("{}", to_json(row));
     }
 }
 
-pub fn dispatch<S, T>(part1: DayFunc<S>, part2: DayFunc<T>) -> Result<()>
-where
-    S: Display,
-    T: Display,
-{
-    let result = run(&part1, &part2)?;
-    println!("{}", result);
+fn run_command(matches: &ArgMatches) -> Result<()> {
+    let selected = parse_days(matches.value_of("days").context("no days given")?)?;
+    let parts = parse_parts(matches.value_of("part").unwrap_or("both"))?;
+    let source = match matches.value_of("input") {
+        Some(_) if selected.len() > 1 => bail!("`--input` can only be used with a single day"),
+        Some("-") => Some(Source::Stdin),
+        Some(filename) => Some(Source::File(filename.into())),
+        None => None,
+    };
+    let runs = match matches.value_of("bench") {
+        Some(raw) => match raw.parse() {
+            Ok(runs) if runs > 0 => runs,
+            _ => bail!("invalid number of runs `{}`", raw),
+        },
+        None => 1,
+    };
+
+    let answers_dir = Path::new("answers");
+
+    let jobs = match matches.value_of("jobs") {
+        Some(raw) => match raw.parse() {
+            Ok(jobs) if jobs > 0 => jobs,
+            _ => bail!("invalid number of jobs `{}`", raw),
+        },
+        None => 1,
+    };
+    let frames_dir = matches.value_of("dump-frames").map(PathBuf::from);
+    let timeout = match matches.value_of("timeout") {
+        Some(raw) => match raw.parse() {
+            Ok(secs) if secs > 0.0 => match Duration::try_from_secs_f64(secs) {
+                Ok(timeout) => Some(timeout),
+                Err(e) => bail!("invalid timeout `{}`: {}", raw, e),
+            },
+            _ => bail!("invalid timeout `{}`", raw),
+        },
+        None => None,
+    };
+
+    // parse every day first, then solve each part on its own, so that parts still share
+    // a single parse but a runaway part only times out itself
+    let mut solvers = vec![];
+    let mut parse_tasks: Vec<pool::Task<Result<ParseRuns>>> = vec![];
+    for &day in &selected {
+        let solver = days::get(day).context(format!("no solution for day {}", day))?;
+        let source = source
+            .clone()
+            .unwrap_or_else(|| Source::File(format!("input/day{:02}", day)));
+        solvers.push(solver);
+        parse_tasks.push(Box::new(move || parse_day(solver, &source, runs)));
+    }
+    let parsed: Vec<_> = pool::run(parse_tasks, jobs, timeout)
+        .into_iter()
+        .map(|result| result.and_then(|parsed| parsed))
+        .collect();
+
+    let mut part_tasks: Vec<pool::Task<Result<SolveRuns>>> = vec![];
+    for ((&day, &solver), parsed) in selected.iter().zip(&solvers).zip(&parsed) {
+        if let Ok((parsed, _)) = parsed {
+            for &part in &parts {
+                let parsed = parsed.clone();
+                let frames_dir = frames_dir.clone();
+                part_tasks.push(Box::new(move || {
+                    solve_part(day, solver, &parsed, part, runs, frames_dir.as_deref())
+                }));
+            }
+        }
+    }
+    let mut solved = pool::run(part_tasks, jobs, timeout).into_iter();
+
+    let mut rows = vec![];
+    for (&day, parsed) in selected.iter().zip(parsed) {
+        let parse_times = match parsed {
+            Ok((_, times)) => times,
+            Err(e) => {
+                // shared by both parts
+                let message = format!("{:#}", e);
+                rows.extend(parts.iter().map(|&part| Row {
+                    day,
+                    part,
+                    result: Err(anyhow!("{}", message)),
+                    check: None,
+                }));
+                continue;
+            }
+        };
+        for &part in &parts {
+            let result = solved.next().context("missing part result")?;
+            let result = result.and_then(|runs| runs).map(|runs| {
+                runs.into_iter()
+                    .zip(&parse_times)
+                    .map(|((answer, solve), &parse)| Timed {
+                        answer,
+                        parse,
+                        solve,
+                    })
+                    .collect()
+            });
+            rows.push(Row {
+                day,
+                part,
+                result,
+                check: None,
+            });
+        }
+    }
+    for row in &mut rows {
+        let answer = match &row.result {
+            Ok(runs) => &runs[0].answer,
+            Err(_) => continue,
+        };
+        if matches.is_present("record") {
+            answers::record(answers_dir, row.day, row.part, answer)?;
+        }
+        if matches.is_present("check") {
+            row.check = Some(answers::check(answers_dir, row.day, row.part, answer)?);
+        }
+    }
+    if matches.value_of("format") == Some("json") {
+        print_json(&rows);
+    } else {
+        if matches.is_present("bench") {
+            print_bench(&rows);
+        } else {
+            print_table(&rows);
+        }
+        let mut shown = vec![];
+        for row in &rows {
+            match (&row.result, &row.check) {
+                (Ok(runs), Some(Check::Wrong { expected })) => {
+                    println!("\nday {} part {}:", row.day, row.part);
+                    print!("{}", answers::diff(expected, &runs[0].answer));
+                }
+                (Err(e), _) => {
+                    // parse errors are shared by both parts, only show them once
+                    let message = format!("{:#}", e);
+                    if message.contains('\n') && !shown.contains(&(row.day, message.clone())) {
+                        println!("\nday {}: {}", row.day, message);
+                        shown.push((row.day, message));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let failed = rows.iter().filter(|row| row.failed()).count();
+    if failed > 0 {
+        bail!("{} of {} parts failed", failed, rows.len());
+    }
     Ok(())
 }
+
+fn fetch_command(matches: &ArgMatches) -> Result<()> {
+    let selected = parse_days(matches.value_of("days").context("no days given")?)?;
+    let mut config = fetch::Config::load(Path::new(fetch::CONFIG_FILE))?;
+    if let Some(base_url) = matches.value_of("base-url") {
+        config.base_url = base_url.into();
+    }
+    for day in selected {
+        let (path, fetched) = fetch::fetch(&config, Path::new("input"), day)?;
+        match fetched {
+            fetch::Fetched::Cached => println!("{} already cached", path.display()),
+            fetch::Fetched::Downloaded => println!("downloaded {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn new_command(matches: &ArgMatches) -> Result<()> {
+    let raw = matches.value_of("day").context("no day given")?;
+    let day: u8 = raw.parse().context(format!("invalid day `{}`", raw))?;
+    if !(1..=25).contains(&day) {
+        bail!("no such day `{}`", day);
+    }
+    let input = match matches.value_of("example") {
+        Some("-") => Some(read_stdin()?),
+        Some(filename) => Some(read_file(filename).context(format!("reading `{}`", filename))?),
+        None => None,
+    };
+    let example = scaffold::Example {
+        input,
+        part1: matches.value_of("part1").map(String::from),
+        part2: matches.value_of("part2").map(String::from),
+    };
+    let path = scaffold::new_day(Path::new("."), day, &example, matches.is_present("force"))?;
+    println!("created {}", path.display());
+    Ok(())
+}
+
+/// Run an Intcode program, printing its output as ASCII
+fn cpu_command(matches: &ArgMatches) -> Result<()> {
+    let program = match matches.value_of("program").context("no program given")? {
+        "-" => read_stdin()?,
+        filename => read_file(filename).context(format!("reading `{}`", filename))?,
+    };
+    let mut cpu: Cpu = program.trim().parse()?;
+    let mut output = String::new();
+    loop {
+        match cpu.run()? {
+            CpuState::Output(value) => output.push(value as u8 as char),
+            CpuState::Halted => break,
+            CpuState::NeedsInput => bail!("the program asked for input"),
+        }
+    }
+    print!("{}", output);
+    Ok(())
+}
+
+fn watch_command(matches: &ArgMatches) -> Result<()> {
+    let day = match parse_days(matches.value_of("day").context("no day given")?)?[..] {
+        [day] => day,
+        _ => bail!("`aoc watch` takes a single day"),
+    };
+    let interval = match matches.value_of("interval") {
+        Some(raw) => raw
+            .parse()
+            .map(Duration::from_millis)
+            .context(format!("invalid interval `{}`", raw))?,
+        None => Duration::from_millis(500),
+    };
+    watch::watch(day, interval)
+}
+
+/// Entry point for the `aoc` binary, which runs any selection of days from the registry
+pub fn aoc() -> Result<()> {
+    let matches = App::new("aoc")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Run one or more days")
+                .arg(
+                    Arg::with_name("days")
+                        .help("A single day, a range like `3..=9`, or `all`")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("part")
+                        .short("p")
+                        .takes_value(true)
+                        .default_value("both")
+                        .possible_values(&["1", "2", "both"])
+                        .help("Which part to run. Both parts share a single parse of the input"),
+                )
+                .arg(
+                    Arg::with_name("input")
+                        .short("i")
+                        .long("input")
+                        .takes_value(true)
+                        .help("Sets the input file to use, or `-` for stdin. Leave out to assume `input/dayNN`"),
+                )
+                .arg(
+                    Arg::with_name("bench")
+                        .long("bench")
+                        .takes_value(true)
+                        .value_name("N")
+                        .help("Run each part N times and print min/median/max parse and solve times in nanoseconds"),
+                )
+                .arg(
+                    Arg::with_name("jobs")
+                        .short("j")
+                        .long("jobs")
+                        .takes_value(true)
+                        .value_name("N")
+                        .help("Run up to N days or parts at the same time. Results are still printed in day order. Parts abandoned by --timeout keep running and aren't counted"),
+                )
+                .arg(
+                    Arg::with_name("timeout")
+                        .long("timeout")
+                        .takes_value(true)
+                        .value_name("SECS")
+                        .help("Stop waiting for a day's parse or one of its parts after this long and report it as an error. It keeps running in the background until aoc exits"),
+                )
+                .arg(
+                    Arg::with_name("dump-frames")
+                        .long("dump-frames")
+                        .takes_value(true)
+                        .value_name("DIR")
+                        .help("Write an image of each simulation step to DIR, for days that draw them"),
+                )
+                .arg(
+                    Arg::with_name("check")
+                        .long("check")
+                        .conflicts_with("record")
+                        .help("Compare answers with the ones recorded in `answers/dayNN.toml`"),
+                )
+                .arg(
+                    Arg::with_name("record")
+                        .long("record")
+                        .conflicts_with("input")
+                        .help("Record answers in `answers/dayNN.toml`. Only from the real inputs, so not with --input"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .default_value("table")
+                        .possible_values(&["table", "json"])
+                        .help("`json` prints one `{day, part, answer, duration_ns, error}` object per line"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("fetch")
+                .about("Download puzzle inputs into `input/`, skipping any that are already there")
+                .arg(
+                    Arg::with_name("days")
+                        .help("A single day, a range like `3..=9`, or `all`")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("base-url")
+                        .long("base-url")
+                        .takes_value(true)
+                        .help("Overrides `AOC_BASE_URL` and `base_url` in `aoc.toml`"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Create `src/days/dayNN.rs` from `day.tpl` and register it")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day to create")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("example")
+                        .long("example")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Example input for `TEST_INPUT`, or `-` for stdin"),
+                )
+                .arg(
+                    Arg::with_name("part1")
+                        .long("part1")
+                        .takes_value(true)
+                        .value_name("ANSWER")
+                        .help("Expected part 1 answer for the example"),
+                )
+                .arg(
+                    Arg::with_name("part2")
+                        .long("part2")
+                        .takes_value(true)
+                        .value_name("ANSWER")
+                        .help("Expected part 2 answer for the example"),
+                )
+                .arg(
+                    Arg::with_name("force")
+                        .long("force")
+                        .help("Overwrite the day if it already exists"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("watch")
+                .about("Rerun a day on its examples and input whenever its source or inputs change")
+                .arg(
+                    Arg::with_name("day")
+                        .help("The day to watch")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::with_name("interval")
+                        .long("interval")
+                        .takes_value(true)
+                        .value_name("MS")
+                        .default_value("500")
+                        .help("How often to poll for changes"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("cpu")
+                .about("Run an Intcode program and print its output as ASCII")
+                .arg(
+                    Arg::with_name("program")
+                        .help("The program file, or `-` for stdin")
+                        .required(true)
+                        .index(1),
+                ),
+        )
+        .get_matches();
+
+    match matches.subcommand() {
+        ("run", Some(sub)) => run_command(sub),
+        ("fetch", Some(sub)) => fetch_command(sub),
+        ("new", Some(sub)) => new_command(sub),
+        ("watch", Some(sub)) => watch_command(sub),
+        ("cpu", Some(sub)) => cpu_command(sub),
+        _ => bail!("unknown command"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() -> Result<()> {
+        assert_eq!(parse_days("5")?, vec![5]);
+        assert_eq!(parse_days("3..=5")?, vec![3, 4, 5]);
+        assert_eq!(parse_days("3..5")?, vec![3, 4]);
+        assert_eq!(parse_days("all")?.len(), 25);
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            stats([ms(5), ms(1), ms(3), ms(9)].into_iter()),
+            (ms(1), ms(5), ms(9))
+        );
+        assert_eq!(stats([ms(2)].into_iter()), (ms(2), ms(2), ms(2)));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let row = Row {
+            day: 13,
+            part: Part::Part2,
+            result: Ok(vec![Timed {
+                answer: "#.\n.#".into(),
+                parse: Duration::from_nanos(3),
+                solve: Duration::from_nanos(4),
+            }]),
+            check: None,
+        };
+        assert_eq!(
+            to_json(&row).to_string(),
+            r##"{"answer":"#.\n.#","day":13,"duration_ns":7,"error":null,"part":2}"##
+        );
+
+        let row = Row {
+            day: 1,
+            part: Part::Part1,
+            result: Err(anyhow!("oops")),
+            check: None,
+        };
+        assert_eq!(
+            to_json(&row).to_string(),
+            r#"{"answer":null,"day":1,"duration_ns":null,"error":"oops","part":1}"#
+        );
+    }
+}
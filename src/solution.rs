@@ -0,0 +1,100 @@
+use crate::frames;
+use crate::input;
+use anyhow::{anyhow, Context, Result};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::Part1 => f.pad("1"),
+            Part::Part2 => f.pad("2"),
+        }
+    }
+}
+
+/// A single day's puzzle: `parse` turns the raw input into something both parts can work on
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// The formatted answer of a single run, with parse and solve measured separately
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// A day's parsed input, which its parts can share across threads
+pub type Parsed = Arc<dyn Any + Send + Sync>;
+
+/// Object safe view of a `Solution`, so days with different types can live in one registry
+pub trait Solver: Sync {
+    /// Parse `input`, with errors pointing at where in it they are
+    fn parse(&self, input: &str) -> Result<Parsed>;
+
+    /// The formatted answer to `part`, from what this day's `parse` returned
+    fn solve_part(&self, parsed: &Parsed, part: Part) -> Result<String>;
+
+    /// Parse `input` once and solve each of `parts` with the result, in order
+    fn solve(&self, parts: &[Part], input: &str) -> Vec<Result<Timed>> {
+        let start = Instant::now();
+        let parsed = match self.parse(input) {
+            Ok(parsed) => parsed,
+            Err(e) => return parts.iter().map(|_| Err(anyhow!("{:#}", e))).collect(),
+        };
+        let parse = start.elapsed();
+
+        parts
+            .iter()
+            .map(|&part| {
+                frames::part(part);
+                let start = Instant::now();
+                let answer = self.solve_part(&parsed, part)?;
+                Ok(Timed {
+                    answer,
+                    parse,
+                    solve: start.elapsed(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        match S::parse(input) {
+            Ok(parsed) => Ok(Arc::new(parsed)),
+            Err(e) => Err(input::locate(input, e)),
+        }
+    }
+
+    fn solve_part(&self, parsed: &Parsed, part: Part) -> Result<String> {
+        let parsed = parsed
+            .downcast_ref::<S::Input>()
+            .context("input parsed by another day")?;
+        match part {
+            Part::Part1 => S::part1(parsed).map(|res| format!("{}", res)),
+            Part::Part2 => S::part2(parsed).map(|res| format!("{}", res)),
+        }
+    }
+}
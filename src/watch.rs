@@ -0,0 +1,190 @@
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Answers by input name and part, errors included so they show up in the diff too
+type Answers = BTreeMap<(String, u64), String>;
+
+/// Every example for `day` followed by the real input
+fn inputs(day: u8) -> Vec<(String, PathBuf)> {
+    let mut inputs: Vec<(String, PathBuf)> = fs::read_dir(format!("examples/day{:02}", day))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension() == Some(OsStr::new("txt")))
+        .map(|path| (path.file_name().unwrap().to_string_lossy().into(), path))
+        .collect();
+    inputs.sort();
+    inputs.push((
+        "input".into(),
+        PathBuf::from(format!("input/day{:02}", day)),
+    ));
+    inputs
+}
+
+/// Files whose modification should trigger a rerun
+fn watched(day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(format!("src/days/day{:02}.rs", day))];
+    paths.extend(inputs(day).into_iter().map(|(_, path)| path));
+    paths
+}
+
+fn mtimes(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Rebuild and run `day` on every input, going through `cargo` so source changes are picked up
+fn run(day: u8) -> Result<Answers> {
+    let profile = if cfg!(debug_assertions) {
+        None
+    } else {
+        Some("--release")
+    };
+    let mut answers = Answers::new();
+    for (name, path) in inputs(day) {
+        if !path.exists() {
+            continue;
+        }
+        let output = Command::new("cargo")
+            .args(["run", "--quiet", "--bin", "aoc"])
+            .args(profile)
+            .args(["--", "run", &day.to_string(), "--format", "json", "--input"])
+            .arg(&path)
+            .output()
+            .context("running cargo")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim().is_empty() {
+            // didn't compile, cargo has the details
+            bail!("{}", String::from_utf8_lossy(&output.stderr).trim_end());
+        }
+        for line in stdout.lines() {
+            let row: Value = serde_json::from_str(line).context("invalid output from `aoc run`")?;
+            let part = row["part"].as_u64().context("no part")?;
+            let answer = match (&row["answer"], &row["error"]) {
+                (Value::String(answer), _) => answer.clone(),
+                (_, Value::String(error)) => error.clone(),
+                _ => "?".into(),
+            };
+            answers.insert((name.clone(), part), answer);
+        }
+    }
+    Ok(answers)
+}
+
+/// Two columns of `previous` and `new` answers, line by line, marking rows that changed
+pub fn side_by_side(previous: &Answers, new: &Answers) -> String {
+    let name_width = new
+        .keys()
+        .map(|(name, _)| name.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or(0);
+    let previous_width = previous
+        .values()
+        .flat_map(|answer| answer.lines())
+        .map(str::len)
+        .chain(["previous".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut output = format!(
+        "{:name_width$}  part  {:previous_width$}  new\n",
+        "input", "previous"
+    );
+    for (key @ (name, part), answer) in new {
+        let old = previous.get(key).map_or("", String::as_str);
+        let marker = if previous.contains_key(key) && old != answer {
+            "  *"
+        } else {
+            ""
+        };
+        let old_lines: Vec<&str> = old.lines().collect();
+        let new_lines: Vec<&str> = answer.lines().collect();
+        for i in 0..old_lines.len().max(new_lines.len()).max(1) {
+            let (name, part, marker) = if i == 0 {
+                (name.as_str(), part.to_string(), marker)
+            } else {
+                ("", String::new(), "")
+            };
+            output.push_str(&format!(
+                "{:name_width$}  {:>4}  {:previous_width$}  {}{}\n",
+                name,
+                part,
+                old_lines.get(i).unwrap_or(&""),
+                new_lines.get(i).unwrap_or(&""),
+                marker
+            ));
+        }
+    }
+    output
+}
+
+/// Rerun `day` whenever its source, examples or input change, polling every `interval`
+pub fn watch(day: u8, interval: Duration) -> Result<()> {
+    let mut previous = Answers::new();
+    let mut paths = watched(day);
+    let mut seen = mtimes(&paths);
+    loop {
+        match run(day) {
+            Ok(answers) => {
+                print!("{}", side_by_side(&previous, &answers));
+                previous = answers;
+            }
+            Err(e) => println!("{:#}", e),
+        }
+        println!("\nwatching {} files for changes", paths.len());
+
+        loop {
+            thread::sleep(interval);
+            // new examples may have appeared
+            let now_watched = watched(day);
+            let now = mtimes(&now_watched);
+            if now_watched != paths || now != seen {
+                paths = now_watched;
+                seen = now;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_side_by_side() {
+        let answers = |entries: &[(&str, u64, &str)]| -> Answers {
+            entries
+                .iter()
+                .map(|&(name, part, answer)| ((name.to_string(), part), answer.to_string()))
+                .collect()
+        };
+        let previous = answers(&[("a.txt", 1, "10"), ("input", 1, "#.\n.#")]);
+        let new = answers(&[
+            ("a.txt", 1, "12"),
+            ("input", 1, "#.\n.#"),
+            ("input", 2, "7"),
+        ]);
+        assert_eq!(
+            side_by_side(&previous, &new),
+            "\
+input  part  previous  new
+a.txt     1  10        12  *
+input     1  #.        #.
+             .#        .#
+input     2            7
+"
+        );
+    }
+}
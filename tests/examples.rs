@@ -0,0 +1,41 @@
+//! One test per `examples/dayNN/NAME.txt`, checked against the answers in the
+//! `NAME.toml` next to it. Only the parts listed in the `.toml` are run.
+use anyhow::{bail, Context, Result};
+use aoc2021::answers;
+use aoc2021::days;
+use aoc2021::solution::Part;
+use std::fs;
+use std::path::PathBuf;
+
+fn check_example(day: u8, case: &str) -> Result<()> {
+    let input_file = format!("{}.txt", case);
+    let input = fs::read_to_string(&input_file).context(format!("reading `{}`", input_file))?;
+    let answers_file = PathBuf::from(format!("{}.toml", case));
+    if !answers_file.exists() {
+        bail!("no answers for `{}`", input_file);
+    }
+
+    let mut parts = vec![];
+    let mut expected = vec![];
+    for part in [Part::Part1, Part::Part2] {
+        if let Some(answer) = answers::expected_in(&answers_file, part)? {
+            parts.push(part);
+            expected.push(answer);
+        }
+    }
+    if parts.is_empty() {
+        bail!("no `part1` or `part2` in `{}`", answers_file.display());
+    }
+
+    let solver = days::get(day).context(format!("no solution for day {}", day))?;
+    for ((part, expected), result) in parts.iter().zip(expected).zip(solver.solve(&parts, &input)) {
+        assert_eq!(
+            result?.answer, expected,
+            "part {} of `{}`",
+            part, input_file
+        );
+    }
+    Ok(())
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,16 @@
+//! One test per crash saved in `fuzz/regressions/TARGET/`, replayed through the same
+//! code as the fuzz target that found it. A test fails if the input still panics.
+use anyhow::{Context, Result};
+use std::fs;
+
+#[allow(dead_code)]
+#[path = "../fuzz/src/lib.rs"]
+mod targets;
+
+fn replay(target: fn(&[u8]), path: &str) -> Result<()> {
+    let data = fs::read(path).context(format!("reading `{}`", path))?;
+    target(&data);
+    Ok(())
+}
+
+include!(concat!(env!("OUT_DIR"), "/fuzz_regressions.rs"));
@@ -0,0 +1,127 @@
+//! Mangle a small valid input for each day and check that parsing and solving it
+//! only ever returns errors, rather than panicking.
+use aoc2021::days;
+use aoc2021::solution::Part;
+use aoc2021::Solution;
+use proptest::prelude::*;
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete(usize),
+    Insert(usize, char),
+    Replace(usize, char),
+    DuplicateLine(usize),
+    DeleteLine(usize),
+}
+
+/// Characters that mean something to at least one of the parsers
+const ALPHABET: &str = "0123456789-,.=#[]<>v \nABCDxyz";
+
+fn edit() -> impl Strategy<Value = Edit> {
+    let c = || prop::sample::select(ALPHABET.chars().collect::<Vec<_>>());
+    prop_oneof![
+        any::<usize>().prop_map(Edit::Delete),
+        (any::<usize>(), c()).prop_map(|(i, c)| Edit::Insert(i, c)),
+        (any::<usize>(), c()).prop_map(|(i, c)| Edit::Replace(i, c)),
+        any::<usize>().prop_map(Edit::DuplicateLine),
+        any::<usize>().prop_map(Edit::DeleteLine),
+    ]
+}
+
+fn edits() -> impl Strategy<Value = Vec<Edit>> {
+    prop::collection::vec(edit(), 1..6)
+}
+
+fn mangle(seed: &str, edits: &[Edit]) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+    for edit in edits {
+        match *edit {
+            Edit::Delete(i) if !chars.is_empty() => {
+                chars.remove(i % chars.len());
+            }
+            Edit::Insert(i, c) => chars.insert(i % (chars.len() + 1), c),
+            Edit::Replace(i, c) if !chars.is_empty() => {
+                let len = chars.len();
+                chars[i % len] = c;
+            }
+            Edit::DuplicateLine(i) | Edit::DeleteLine(i) => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.lines().collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                let i = i % lines.len();
+                if let Edit::DuplicateLine(_) = edit {
+                    lines.insert(i, lines[i]);
+                } else {
+                    lines.remove(i);
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Parse and solve `input` as `day`; any panic fails the test
+fn solve(day: u8, input: &str) {
+    let solver = days::get(day).unwrap();
+    for result in solver.solve(&[Part::Part1, Part::Part2], input) {
+        if let Err(e) = result {
+            // errors have to say something
+            assert!(!format!("{:#}", e).is_empty());
+        }
+    }
+}
+
+macro_rules! malformed {
+    ($($name:ident: $day:expr, $seed:expr;)*) => {
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(64))]
+            $(
+                #[test]
+                fn $name(edits in edits()) {
+                    solve($day, &mangle(&$seed, &edits));
+                }
+            )*
+        }
+    };
+}
+
+malformed! {
+    day01: 1, "199\n200\n208\n210\n200";
+    day02: 2, "forward 5\ndown 5\nup 3";
+    day03: 3, "00100\n11110\n10110\n10111";
+    day04: 4, "7,4,9,5,11\n\n22 13 17 11 0\n8 2 23 4 24\n21 9 14 16 7\n6 10 3 18 5\n1 12 20 15 19";
+    day05: 5, "0,9 -> 5,9\n8,0 -> 0,8\n2,2 -> 2,1";
+    day06: 6, "3,4,3,1,2";
+    day07: 7, "16,1,2,0,4,2,7,1,2,14";
+    day08: 8, "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+    day09: 9, "2199943210\n3987894921\n9856789892";
+    day10: 10, "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>";
+    day11: 11, "11111\n19991\n19191\n19991\n11111";
+    day12: 12, "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
+    day13: 13, "6,10\n0,14\n9,10\n0,3\n\nfold along y=7\nfold along x=5";
+    day14: 14, "NNCB\n\nCH -> B\nNN -> C\nNC -> B\nCB -> H";
+    day15: 15, "1163\n1381\n2136\n3694";
+    day16: 16, "9C0141080250320F1802104A08";
+    day17: 17, "target area: x=20..30, y=-10..-5";
+    day18: 18, "[[1,2],3]\n[4,[5,[6,7]]]\n[[[[1,1],2],3],4]";
+    day19: 19, "--- scanner 0 ---\n404,-588,-901\n528,-643,409\n\n--- scanner 1 ---\n686,422,578";
+    day20: 20, format!("{}\n\n#..#.\n#....\n##..#", "..#.#..#####.#.#.#.###.##.".repeat(20).get(..512).unwrap());
+    day21: 21, "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+    day22: 22, "on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13\noff x=9..11,y=9..11,z=9..11";
+    day25: 25, "v...>>.vv>\n.vv>>.vv..\n>>.>v>...v";
+}
+
+// the search for day 23 is too slow to run on every case, and day 24 does all its work in
+// `parse`, so it's checked in its own module instead
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+    #[test]
+    fn day23(edits in edits()) {
+        let seed = "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########";
+        let _ = days::Day23::parse(&mangle(seed, &edits));
+    }
+}